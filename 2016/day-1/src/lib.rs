@@ -0,0 +1,431 @@
+use aoc_core::{random::Rng, Answer, Drawing, ParseError, Solution};
+use geometry::{Cardinal, Point2, Turn};
+use grid::{Grid, Pos};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A street corner, in blocks east (x) and north (y) of where we were dropped off. An instruction
+/// walks at most `u32::MAX` blocks, so it would take billions of them to go past `i64`.
+pub type Intersection = Point2<i64>;
+
+// move is apparently reserved keyword
+fn mv(intersection: Intersection, cd: Cardinal, steps: u32) -> Intersection {
+  intersection + cd.vector() * steps as i64
+}
+
+// Instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction   { direction: Turn, steps: u32 }
+
+impl fmt::Display for Instruction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}{}", self.direction, self.steps) }  
+}
+
+impl Instruction {
+  fn nothing() -> Instruction { 
+    Instruction { direction: Turn::Straight, steps: 0 } 
+  }
+
+  /// Parse "R8" or "L12".
+  pub fn parse(s: &str) -> Result<Instruction, ParseError> {
+    let s = s.trim();
+    let (turn, sub) = s.split_at(s.chars().next().map_or(0, char::len_utf8));
+
+    let direction = match turn {
+      "R" => Turn::Right,
+      "L" => Turn::Left,
+      _   => return Err(ParseError::within(s, turn, "a turn, L or R")),
+    };
+
+    // Digits only: `u32` would take a leading `+` too.
+    let steps = Some(sub)
+      .filter(|sub| sub.bytes().all(|b| b.is_ascii_digit()))
+      .and_then(|sub| u32::from_str(sub).ok())
+      .ok_or_else(|| ParseError::within(s, sub, format!("a number of blocks up to {}", u32::MAX)))?;
+
+    Ok(Instruction { direction, steps })
+  }
+}
+// END Instruction
+
+// Frame
+// instruction begat frame
+struct Frame { 
+  index: i32, 
+
+  // Current Intersection.
+  intersection: Intersection,
+  // Current Cardinal direction.
+  cardinal_direction: Cardinal, 
+
+  // The Instruction that directed you to intersection/cardinal_direction.
+  instruction: Instruction 
+}
+
+impl Frame {
+  fn apply(frame: &Frame, instruction: Instruction) -> Frame {
+    let new_cardinal_direction = frame.cardinal_direction.turn(instruction.direction);
+
+    Frame { 
+      index: frame.index + 1, 
+      intersection: mv(frame.intersection, new_cardinal_direction, instruction.steps),
+      cardinal_direction: new_cardinal_direction,
+      instruction 
+    }
+  }
+}
+
+impl fmt::Display for Frame {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { 
+    write!(f, "F({}) {} -> {}{}", self.index, self.instruction, self.cardinal_direction, self.intersection)
+  }  
+}
+// END Frame
+
+fn build_frames(moves: &Vec<Instruction>) -> Vec<Frame> {
+  let mut frames = Vec::with_capacity(moves.len());
+
+  let first_frame = Frame { 
+    index: 0, 
+    intersection: Intersection::origin(),
+    cardinal_direction: Cardinal::North, 
+    instruction: Instruction::nothing() 
+  };
+
+  frames.push(first_frame);
+
+  for mv in moves {
+    let frame;
+    let instruction = mv.clone();
+
+    {
+      // We need this scope here to avoid immutable/mutable borrow on frames.
+      let last_frame = frames.last().unwrap();
+
+      frame = Frame::apply(last_frame, instruction);
+    }
+
+    frames.push(frame);
+  }
+
+  frames
+}
+
+// Segment
+// A straight stretch of the walk, from one frame's intersection to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment { from: Intersection, to: Intersection }
+
+impl Segment {
+  fn between(a: &Frame, b: &Frame) -> Segment {
+    Segment { from: a.intersection, to: b.intersection }
+  }
+
+  /// The first intersection this segment walks into that `earlier` covers too, if any. Both
+  /// are lines along an axis, so the blocks they share (crossing or lying along each other) make
+  /// a box, and the first one walked into is the corner of it nearest `from`.
+  fn first_overlap(&self, earlier:&Segment) -> Option<Intersection> {
+    if self.from == self.to { return None; }
+
+    // Everything walked into, which is all but the intersection we started at.
+    let step = Intersection::new((self.to.x - self.from.x).signum(), (self.to.y - self.from.y).signum());
+    let (lo, hi) = bounds(self.from + step, self.to);
+    let (earlier_lo, earlier_hi) = bounds(earlier.from, earlier.to);
+
+    let lo = Intersection::new(lo.x.max(earlier_lo.x), lo.y.max(earlier_lo.y));
+    let hi = Intersection::new(hi.x.min(earlier_hi.x), hi.y.min(earlier_hi.y));
+    if lo.x > hi.x || lo.y > hi.y { return None; }
+
+    Some(Intersection::new(self.from.x.clamp(lo.x, hi.x), self.from.y.clamp(lo.y, hi.y)))
+  }
+}
+
+/// The corners of the box with `a` and `b` at opposite corners, smallest first.
+fn bounds(a: Intersection, b: Intersection) -> (Intersection, Intersection) {
+  (Intersection::new(a.x.min(b.x), a.y.min(b.y)), Intersection::new(a.x.max(b.x), a.y.max(b.y)))
+}
+// END Segment
+
+/// The first intersection walked through twice. Each segment is checked against all the ones
+/// before it, so the time grows with the square of the number of instructions (a few hundred
+/// in a real input), though not with how many blocks they walk.
+fn first_revisit(frames:&[Frame]) -> Option<Intersection> {
+  let segments:Vec<Segment> = frames.windows(2).map(|w| Segment::between(&w[0], &w[1])).collect();
+
+  for (i, segment) in segments.iter().enumerate() {
+    let revisit = segments[..i].iter()
+      .filter_map(|earlier| segment.first_overlap(earlier))
+      .min_by_key(|&intersection| intersection.manhattan(segment.from));
+
+    if revisit.is_some() { return revisit; }
+  }
+
+  None
+}
+
+// Drawing
+/// Most characters across and down the ASCII map. Bigger walks are scaled down to fit.
+const MAP_WIDTH:i64 = 100;
+const MAP_HEIGHT:i64 = 50;
+
+/// The smallest box holding the whole walk, as its south west and north east corners.
+fn extent(frames:&[Frame]) -> (Intersection, Intersection) {
+  frames.iter().fold((frames[0].intersection, frames[0].intersection), |(lo, hi), f| {
+    (bounds(lo, f.intersection).0, bounds(hi, f.intersection).1)
+  })
+}
+
+/// The whole walk as ASCII art with north up, scaled down to fit MAP_WIDTH x MAP_HEIGHT if it
+/// doesn't already. `O` is where we started, `X` the first intersection visited twice and `*`
+/// where the instructions end.
+fn ascii_map(frames:&[Frame], revisit:Option<Intersection>) -> String {
+  let (lo, hi) = extent(frames);
+  let (span_x, span_y) = (hi.x - lo.x, hi.y - lo.y);
+  let (cols, rows) = ((span_x + 1).min(MAP_WIDTH), (span_y + 1).min(MAP_HEIGHT));
+
+  // The character an intersection lands on.
+  let cell = |i:Intersection| -> Pos {
+    let scale = |d:i64, span:i64, n:i64| if span == 0 { 0 } else { (d * (n - 1) / span) as usize };
+    (scale(i.x - lo.x, span_x, cols), scale(hi.y - i.y, span_y, rows))
+  };
+
+  let mut map:Grid<char> = Grid::filled(cols as usize, rows as usize, ' ');
+  for w in frames.windows(2) {
+    let (from, to) = (cell(w[0].intersection), cell(w[1].intersection));
+    let line = if from.1 == to.1 { '-' } else { '|' };
+
+    for x in from.0.min(to.0)..=from.0.max(to.0) {
+      for y in from.1.min(to.1)..=from.1.max(to.1) {
+        // Where the walk crosses itself.
+        let c = match map.get((x, y)) { Some(&' ') => line, Some(&c) if c == line => line, _ => '+' };
+        map.set((x, y), c);
+      }
+    }
+  }
+
+  let (start, end) = (frames[0].intersection, frames[frames.len() - 1].intersection);
+  for f in frames { map.set(cell(f.intersection), '+'); }
+  map.set(cell(end), '*');
+  map.set(cell(start), 'O');
+  if let Some(revisit) = revisit { map.set(cell(revisit), 'X'); }
+
+  let art:Vec<String> = map.render(|c| *c.unwrap_or(&' ')).lines().map(|l| l.trim_end().to_string()).collect();
+
+  let mut legend = format!("O start {}, * end {}", start, end);
+  if let Some(revisit) = revisit { legend += &format!(", X first visited twice {}", revisit); }
+  if cols <= span_x || rows <= span_y {
+    legend += &format!("\nscaled down from {} x {} intersections", span_x + 1, span_y + 1);
+  }
+
+  format!("{}\n\n{}", art.join("\n"), legend)
+}
+
+/// The whole walk as an SVG polyline with north up. Dots mark the start (green), the first
+/// intersection visited twice (red) and the end (blue); hovering over one gives its coordinates.
+fn svg(frames:&[Frame], revisit:Option<Intersection>) -> String {
+  let (lo, hi) = extent(frames);
+  let (span_x, span_y) = (hi.x - lo.x, hi.y - lo.y);
+  let margin = span_x.max(span_y) / 20 + 1;
+  let radius = (span_x.max(span_y) as f64 / 80.0).max(0.3);
+
+  // SVG's y grows downwards, so north is -y.
+  let point = |i:Intersection| format!("{},{}", i.x, -i.y);
+  let dot = |i:Intersection, colour:&str, what:&str| {
+    format!(
+      r#"  <circle cx="{}" cy="{}" r="{}" fill="{}"><title>{} {}</title></circle>"#,
+      i.x, -i.y, radius, colour, what, i
+    )
+  };
+
+  let points:Vec<String> = frames.iter().map(|f| point(f.intersection)).collect();
+  let mut svg = vec![
+    format!(
+      r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="800">"#,
+      lo.x - margin, -hi.y - margin, span_x + 2 * margin, span_y + 2 * margin
+    ),
+    format!(
+      r#"  <polyline points="{}" fill="none" stroke="black" stroke-width="1.5" stroke-linejoin="round" vector-effect="non-scaling-stroke"/>"#,
+      points.join(" ")
+    ),
+    dot(frames[0].intersection, "green", "start"),
+  ];
+  if let Some(revisit) = revisit { svg.push(dot(revisit, "red", "first visited twice")); }
+  svg.push(dot(frames[frames.len() - 1].intersection, "blue", "end"));
+  svg.push("</svg>".to_string());
+
+  svg.join("\n")
+}
+// END Drawing
+
+// Plan
+/// Limits on the instructions [`plan`] comes up with.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlanOptions {
+  /// Never walk through the same intersection twice, where we started included.
+  pub no_revisits: bool,
+  /// The most blocks any one instruction walks, or `None` for no limit.
+  pub max_steps: Option<u32>,
+}
+
+/// The fewest instructions that walk from the origin, starting out facing `facing`, to `target`.
+/// Every instruction walks at least a block, like the real ones do. `None` if `options` leave no
+/// way there, which only happens when `max_steps` is 0.
+///
+/// Every instruction turns, so the walk goes east-west and north-south by turns, and each turn
+/// can be made either way. So the question is just how few blocks-per-instruction sums reach
+/// `target.x` and `target.y`, with the axis we aren't facing along going first.
+pub fn plan(target:Intersection, facing:Cardinal, options:PlanOptions) -> Option<Vec<Instruction>> {
+  if target == Intersection::origin() { return Some(vec![]); }
+
+  let max = options.max_steps.unwrap_or(u32::MAX) as i64;
+  if max == 0 { return None; }
+
+  let x_first = matches!(facing, Cardinal::North | Cardinal::South);
+  let (x, y) = (target.x, target.y);
+
+  for n in 1.. {
+    let (x_moves, y_moves) = if x_first { ((n + 1) / 2, n / 2) } else { (n / 2, (n + 1) / 2) };
+
+    // Going one way only along either axis, the walk can't come back anywhere it's been.
+    let (x_one_way, y_one_way) = (x.abs() >= x_moves, y.abs() >= y_moves);
+    if options.no_revisits && !x_one_way && !y_one_way { return None; }
+
+    if !reachable(x, x_moves, max) || !reachable(y, y_moves, max) { continue; }
+
+    let (xs, ys) = (split(x, x_moves, max), split(y, y_moves, max));
+    return Some(instructions(facing, x_first, &xs, &ys));
+  }
+
+  unreachable!()
+}
+
+/// Whether `moves` moves of 1 to `max` blocks, either way, can add up to `blocks`.
+fn reachable(blocks:i64, moves:i64, max:i64) -> bool {
+  match (moves, max) {
+    (0, _) => blocks == 0,
+    // Every move changes which of odd or even we're at.
+    (_, 1) => blocks.abs() <= moves && (moves - blocks.abs()) % 2 == 0,
+    (1, _) => (1..=max).contains(&blocks.abs()),
+    // Two moves or more can back off each other by anything up to their total.
+    _      => blocks.abs() <= moves * max,
+  }
+}
+
+/// `moves` moves of 1 to `max` blocks (negative going back) adding up to `blocks`, which has to be
+/// [`reachable`]. All going the same way if `blocks` is far enough away for that, biggest first.
+fn split(blocks:i64, moves:i64, max:i64) -> Vec<i64> {
+  let mut left = blocks;
+  let mut split = Vec::with_capacity(moves as usize);
+
+  for i in 0..moves {
+    let rest = moves - i - 1;
+
+    let step = if left.abs() >= moves - i {
+      // Leave at least a block for each move after this one.
+      left.signum() * max.min(left.abs() - rest)
+    } else {
+      // Too close to go one way only: any move the rest can still make up for will do.
+      let near = left.clamp(-max, max);
+      [near, near - 1, near + 1, near - 2, near + 2, 1, -1].into_iter()
+        .find(|&s| s != 0 && s.abs() <= max && reachable(left - s, rest, max))
+        .expect("blocks should be reachable")
+    };
+
+    split.push(step);
+    left -= step;
+  }
+
+  split
+}
+
+/// Turn east-west moves `xs` and north-south moves `ys` into instructions, taking them by turns.
+fn instructions(facing:Cardinal, x_first:bool, xs:&[i64], ys:&[i64]) -> Vec<Instruction> {
+  let mut heading = facing;
+  let (mut xs, mut ys) = (xs.iter(), ys.iter());
+
+  (0..xs.len() + ys.len()).map(|i| {
+    let (step, towards) = if (i % 2 == 0) == x_first {
+      let &step = xs.next().unwrap();
+      (step, if step > 0 { Cardinal::East } else { Cardinal::West })
+    } else {
+      let &step = ys.next().unwrap();
+      (step, if step > 0 { Cardinal::North } else { Cardinal::South })
+    };
+
+    let direction = if heading.turn(Turn::Left) == towards { Turn::Left } else { Turn::Right };
+    heading = towards;
+
+    Instruction { direction, steps: step.unsigned_abs() as u32 }
+  }).collect()
+}
+// END Plan
+
+
+/// No Time for a Taxicab
+pub struct Taxicab;
+
+impl Solution for Taxicab {
+  type Input = Vec<Instruction>;
+
+  fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let lines = aoc_core::parse_lines(input, |line| {
+      line.split(',')
+        .map(|s| Instruction::parse(s).map_err(|e| e.in_context(line, s.trim())))
+        .collect::<Result<Vec<Instruction>, ParseError>>()
+    })?;
+
+    Ok(lines.into_iter().flatten().collect())
+  }
+
+  fn part1(moves: &Vec<Instruction>) -> Answer {
+    let frames: Vec<Frame> = build_frames(moves);
+    let origin = Intersection::origin();
+
+    let last_frame = frames.last().unwrap();
+    let pt1_bunny_hq_intersection = last_frame.intersection; 
+
+    let pt1_bunny_hq_distance_in_blocks = pt1_bunny_hq_intersection.manhattan(origin);
+
+    Answer::from(pt1_bunny_hq_distance_in_blocks)
+      .with_detail(format!("Bunny HQ is {} blocks away", pt1_bunny_hq_distance_in_blocks))
+  }
+
+  fn part2(moves: &Vec<Instruction>) -> Answer {
+    let frames: Vec<Frame> = build_frames(moves);
+    let origin = Intersection::origin();
+
+    match first_revisit(&frames) {
+      Some(pt2_bunny_hq_intersection) => {
+        let pt2_bunny_hq_distance_in_blocks = pt2_bunny_hq_intersection.manhattan(origin);
+
+        Answer::from(pt2_bunny_hq_distance_in_blocks).with_detail(format!(
+          "Bunny HQ {} is {} blocks away",
+          pt2_bunny_hq_intersection,
+          pt2_bunny_hq_distance_in_blocks
+        ))
+      },
+      None => Answer::from("none").with_detail("No intersection was visited twice"),
+    }
+  }
+
+  /// The whole walk, with the first intersection visited twice marked.
+  fn draw(moves: &Vec<Instruction>, drawing: Drawing) -> Option<String> {
+    let frames: Vec<Frame> = build_frames(moves);
+    let revisit = first_revisit(&frames);
+
+    Some(match drawing {
+      Drawing::Text => ascii_map(&frames, revisit),
+      Drawing::Svg  => svg(&frames, revisit),
+    })
+  }
+
+  /// `size` turns, of up to 200 blocks each.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let moves:Vec<String> = (0..size.unwrap_or(150).max(1))
+      .map(|_| format!("{}{}", rng.pick(&["L", "R"]), rng.range(1..=200)))
+      .collect();
+
+    Some(moves.join(", "))
+  }
+}
@@ -0,0 +1,50 @@
+//! The walk drawn as a map and as an SVG.
+
+use aoc_2016_day_1::Taxicab;
+use aoc_core::{Drawing, Solution};
+
+fn draw(input: &str, drawing: Drawing) -> String {
+    let moves = Taxicab::parse(input).unwrap_or_else(|e| panic!("{}", e));
+    Taxicab::draw(&moves, drawing).expect("can draw")
+}
+
+#[test]
+fn taxicab_map() {
+    let map = draw("R8, R4, R4, R8", Drawing::Text);
+    let expected = [
+        "    *",
+        "    |",
+        "    |",
+        "    |",
+        "O---X---+",
+        "    |   |",
+        "    |   |",
+        "    |   |",
+        "    +---+",
+        "",
+        "O start (0, 0), * end (4, 4), X first visited twice (4, 0)",
+    ];
+
+    assert_eq!(map, expected.join("\n"));
+}
+
+#[test]
+fn taxicab_map_scales_down() {
+    let map = draw("R1000, L1000", Drawing::Text);
+    let lines: Vec<&str> = map.lines().collect();
+
+    assert_eq!(lines.len(), 50 + 3);
+    assert!(lines.iter().all(|l| l.len() <= 100));
+    assert_eq!(lines.last(), Some(&"scaled down from 1001 x 1001 intersections"));
+}
+
+#[test]
+fn taxicab_svg() {
+    let svg = draw("R8, R4, R4, R8", Drawing::Svg);
+
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.ends_with("</svg>"));
+    // North is up, and SVG's y grows down.
+    assert!(svg.contains(r#"points="0,0 8,0 8,4 4,4 4,-4""#));
+    assert!(svg.contains("<title>first visited twice (4, 0)</title>"));
+}
@@ -0,0 +1,119 @@
+//! 2016/1's planner, checked by walking its plans block by block and against a breadth-first
+//! search for the fewest instructions.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_2016_day_1::{plan, Instruction, Intersection, PlanOptions, Taxicab};
+use aoc_core::Solution;
+use geometry::{Cardinal, Turn};
+
+/// An instruction's turn and blocks, read back from how it prints.
+fn read(instruction: &Instruction) -> (Turn, i64) {
+    let text = instruction.to_string();
+    let (turn, steps) = text.split_at(1);
+    (if turn == "L" { Turn::Left } else { Turn::Right }, steps.parse().unwrap())
+}
+
+/// Every intersection walked into, in order, starting out facing `facing`.
+fn walk(moves: &[Instruction], facing: Cardinal) -> Vec<Intersection> {
+    let mut heading = facing;
+    let mut visited = vec![Intersection::origin()];
+
+    for instruction in moves {
+        let (turn, steps) = read(instruction);
+        heading = heading.turn(turn);
+
+        for _ in 0..steps {
+            let here = *visited.last().unwrap();
+            visited.push(here + heading.vector());
+        }
+    }
+
+    visited
+}
+
+/// The fewest instructions of 1 to `max` blocks to each intersection near the origin, revisits
+/// and all.
+fn fewest(facing: Cardinal, max: i64) -> HashMap<Intersection, usize> {
+    const BOUND: i64 = 40;
+
+    let mut fewest = HashMap::new();
+    let mut seen = HashSet::from([(Intersection::origin(), facing)]);
+    let mut queue = VecDeque::from([(Intersection::origin(), facing, 0)]);
+
+    while let Some((here, heading, n)) = queue.pop_front() {
+        fewest.entry(here).or_insert(n);
+
+        for turn in [Turn::Left, Turn::Right] {
+            let heading = heading.turn(turn);
+            for steps in 1..=max {
+                let there = here + heading.vector() * steps;
+                if there.x.abs() <= BOUND && there.y.abs() <= BOUND && seen.insert((there, heading)) {
+                    queue.push_back((there, heading, n + 1));
+                }
+            }
+        }
+    }
+
+    fewest
+}
+
+#[test]
+fn plans_are_shortest() {
+    for facing in Cardinal::ALL {
+        for max_steps in [Some(1), Some(2), Some(3), None] {
+            let fewest = fewest(facing, max_steps.map_or(30, |m| m as i64));
+
+            for no_revisits in [false, true] {
+                let options = PlanOptions { no_revisits, max_steps };
+
+                for x in -10..=10 {
+                    for y in -10..=10 {
+                        let target = Intersection::new(x, y);
+                        let context = format!("to {} facing {} with {:?}", target, facing, options);
+                        let moves = plan(target, facing, options).unwrap_or_else(|| panic!("no plan {}", context));
+                        let visited = walk(&moves, facing);
+
+                        assert_eq!(visited.last(), Some(&target), "plan {} went astray", context);
+                        assert_eq!(moves.len(), fewest[&target], "plan {} isn't shortest", context);
+                        assert!(moves.iter().all(|m| read(m).1 >= 1), "plan {} stands still", context);
+
+                        if no_revisits {
+                            let unique: HashSet<&Intersection> = visited.iter().collect();
+                            assert_eq!(unique.len(), visited.len(), "plan {} revisits", context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn plans_keep_to_max_steps() {
+    let options = PlanOptions { no_revisits: true, max_steps: Some(7) };
+    let moves = plan(Intersection::new(-250, 1000), Cardinal::East, options).unwrap();
+
+    assert!(moves.iter().all(|m| read(m).1 <= 7));
+    assert_eq!(walk(&moves, Cardinal::East).last(), Some(&Intersection::new(-250, 1000)));
+}
+
+#[test]
+fn nowhere_to_go() {
+    let options = PlanOptions { no_revisits: false, max_steps: Some(0) };
+
+    assert_eq!(plan(Intersection::origin(), Cardinal::North, options), Some(vec![]));
+    assert_eq!(plan(Intersection::new(1, 0), Cardinal::North, options), None);
+}
+
+#[test]
+fn plans_make_inputs_with_known_answers() {
+    let options = PlanOptions { no_revisits: true, max_steps: None };
+    let moves = plan(Intersection::new(-7, 12), Cardinal::North, options).unwrap();
+    let input: Vec<String> = moves.iter().map(Instruction::to_string).collect();
+    assert_eq!(input.join(", "), "L7, R12");
+
+    let moves = Taxicab::parse(&input.join(", ")).unwrap();
+    assert_eq!(Taxicab::part1(&moves).value(), "19");
+    assert_eq!(Taxicab::part2(&moves).value(), "none");
+}
@@ -0,0 +1,252 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+use geometry::Cardinal;
+use grid::{Pos, SparseGrid};
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+/// Moves are `U`, `R`, `D` and `L`.
+fn parse_move(c: char) -> Option<Cardinal> {
+  match c {
+    'U' | 'R' | 'D' | 'L' => Cardinal::parse(c),
+     _  => None
+  }
+}
+
+/// The letter [`parse_move`] reads as `d`.
+fn move_letter(d: Cardinal) -> char {
+  match d {
+    Cardinal::North => 'U',
+    Cardinal::East  => 'R',
+    Cardinal::South => 'D',
+    Cardinal::West  => 'L',
+  }
+}
+
+/// The keypad part 1 expects: a plain 3 x 3 one.
+pub const PART_ONE: &str = "
+123
+456
+789
+";
+
+/// The keypad that's actually on the bathroom door: this weird ass star thing.
+pub const PART_TWO: &str = "
+  1
+ 234
+56789
+ ABC
+  D
+";
+
+// Origin: upper-left.
+pub struct Keypad { buttons: SparseGrid<char>, cursor: Pos }
+
+impl Keypad {
+  /// A keypad drawn as ASCII art, one row of buttons per line, starting on the `start` button.
+  /// Every other character is a button with that label, except spaces, which are gaps with no
+  /// button. Labels can't repeat, and `start` has to be one of them.
+  ///
+  /// Repeated labels are reported by row and column of the art, not counting blank lines around
+  /// it.
+  pub fn from_art(art: &str, start: char) -> Result<Keypad, KeypadError> {
+    let buttons = SparseGrid::parse(art, |c| if c == ' ' { None } else { Some(c) });
+
+    let mut labels:HashSet<char> = HashSet::new();
+    for y in 0..buttons.height() {
+      for x in 0..buttons.width() {
+        match buttons.get((x, y)) {
+          Some(&c) if !labels.insert(c) => {
+            return Err(KeypadError::Art(ParseError::new(y + 1, x + 1, c, "a label no other button has")));
+          }
+          _ => {}
+        }
+      }
+    }
+
+    let cursor = buttons.position(|&c| c == start).ok_or(KeypadError::NoStart(start))?;
+
+    Ok(Keypad { buttons, cursor })
+  }
+
+  pub fn new_part_one() -> Keypad { Keypad::from_art(PART_ONE, '5').expect("part 1 keypad") }
+
+  pub fn new_part_two() -> Keypad { Keypad::from_art(PART_TWO, '5').expect("part 2 keypad") }
+
+  /// Move one button `d`, unless there's no button there, and say which button we're on.
+  pub fn mv(&mut self, d: Cardinal) -> char {
+    if let Some(nc) = self.neighbour(self.cursor, d) { self.cursor = nc; }
+    self.v()
+  }
+
+  /// The button next to `pos` going `d`, if there is one.
+  fn neighbour(&self, pos: Pos, d: Cardinal) -> Option<Pos> {
+    self.buttons.step(pos, d.screen_vector().into()).filter(|&nc| self.buttons.get(nc).is_some())
+  }
+
+  /// The button we're on.
+  pub fn v(&self) -> char { *(self.buttons.get(self.cursor).unwrap()) }
+
+  /// Follow each line of moves in turn, pressing the button each one ends on.
+  pub fn code(&mut self, lines: &[Vec<Cardinal>]) -> String {
+    let mut codes:Vec<char> = Vec::new();
+
+    for line in lines.iter() {
+      for &d in line { self.mv(d); }
+      codes.push(self.v());
+    }
+
+    let code_strings:Vec<String> = codes.iter().map(|i| { i.to_string() }).collect();
+    code_strings.join("")
+  }
+
+  /// The other way round from [`Keypad::code`]: the shortest line of moves for each button of
+  /// `code`, starting from the button we're on, that types it. Where more than one line is as
+  /// short, the one taken tries U, R, D and L in that order at every move, and `ties` counts them.
+  pub fn typing(&self, code: &str) -> Result<Vec<Typed>, TypingError> {
+    let mut at = self.cursor;
+
+    code.chars().map(|button| {
+      let to = self.buttons.position(|&c| c == button).ok_or(TypingError::NoSuchButton(button))?;
+      let distances = self.distances(to);
+
+      let &(mut left, ties) = distances.get(&at)
+        .ok_or(TypingError::Unreachable { from: *self.buttons.get(at).unwrap(), to: button })?;
+
+      // Every move of a shortest line takes us a button closer.
+      let mut moves:Vec<Cardinal> = Vec::with_capacity(left);
+      while left > 0 {
+        let (d, nc) = Cardinal::ALL.iter()
+          .filter_map(|&d| self.neighbour(at, d).map(|nc| (d, nc)))
+          .find(|(_, nc)| distances.get(nc).is_some_and(|&(n, _)| n == left - 1))
+          .unwrap();
+
+        moves.push(d);
+        at = nc;
+        left -= 1;
+      }
+
+      Ok(Typed { button, moves, ties })
+    }).collect()
+  }
+
+  /// Breadth first from `to`: for every button with a way to it, the fewest moves there and how
+  /// many lines of moves that few there are.
+  fn distances(&self, to: Pos) -> HashMap<Pos, (usize, u64)> {
+    let mut distances:HashMap<Pos, (usize, u64)> = HashMap::from([(to, (0, 1))]);
+    let mut queue:VecDeque<Pos> = VecDeque::from([to]);
+
+    // Everything `n` away comes off the queue before anything further, so each button's count
+    // is finished by the time it comes off.
+    while let Some(pos) = queue.pop_front() {
+      let (n, ways) = distances[&pos];
+
+      for d in Cardinal::ALL {
+        let Some(nc) = self.neighbour(pos, d) else { continue };
+
+        match distances.get_mut(&nc) {
+          None => {
+            distances.insert(nc, (n + 1, ways));
+            queue.push_back(nc);
+          }
+          Some((m, more)) if *m == n + 1 => *more = more.saturating_add(ways),
+          Some(_) => {}
+        }
+      }
+    }
+
+    distances
+  }
+}
+
+/// One line of moves typing a button, from [`Keypad::typing`]. Prints as the line of input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Typed {
+  pub button: char,
+  /// As few as there can be. None at all when we're on `button` already, which the first and
+  /// last lines of an input can't say: blank lines around the input are ignored.
+  pub moves: Vec<Cardinal>,
+  /// How many lines of moves are this short, this one included.
+  pub ties: u64,
+}
+
+impl fmt::Display for Typed {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    self.moves.iter().try_for_each(|&d| write!(f, "{}", move_letter(d)))
+  }
+}
+
+/// Why [`Keypad::from_art`] couldn't make a keypad.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeypadError {
+  /// Something's wrong at a spot in the art.
+  Art(ParseError),
+  /// No button has the label we're meant to start on.
+  NoStart(char),
+}
+
+impl fmt::Display for KeypadError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      KeypadError::Art(ref e) => write!(f, "{}", e),
+      KeypadError::NoStart(start) => write!(f, "there's no {} button to start on", start),
+    }
+  }
+}
+
+impl Error for KeypadError {}
+
+/// Why [`Keypad::typing`] couldn't type a code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypingError {
+  /// No button on the keypad has this label.
+  NoSuchButton(char),
+  /// Gaps cut the button off from the one before it.
+  Unreachable { from: char, to: char },
+}
+
+impl fmt::Display for TypingError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      TypingError::NoSuchButton(button) => write!(f, "there's no {} button", button),
+      TypingError::Unreachable { from, to } => write!(f, "there's no way from {} to {}", from, to),
+    }
+  }
+}
+
+impl Error for TypingError {}
+
+impl Solution for Keypad {
+  type Input = Vec<Vec<Cardinal>>;
+
+  fn parse(input: &str) -> Result<Vec<Vec<Cardinal>>, ParseError> {
+    aoc_core::parse_lines(input, |line| {
+      let line = line.trim();
+      line.char_indices()
+        .map(|(i, c)| {
+          parse_move(c).ok_or_else(|| ParseError::within(line, &line[i..i + c.len_utf8()], "U, R, D or L"))
+        })
+        .collect()
+    })
+  }
+
+  fn part1(lines: &Vec<Vec<Cardinal>>) -> Answer {
+    let mut keypad_part_one = Keypad::new_part_one();
+    keypad_part_one.code(lines).into()
+  }
+
+  fn part2(lines: &Vec<Vec<Cardinal>>) -> Answer {
+    let mut keypad_part_two = Keypad::new_part_two();
+    keypad_part_two.code(lines).into()
+  }
+
+  /// `size` lines, one per button, of a few hundred moves each.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let lines:Vec<String> = (0..size.unwrap_or(5).max(1))
+      .map(|_| (0..rng.range(100..=600)).map(|_| *rng.pick(&['U', 'R', 'D', 'L'])).collect())
+      .collect();
+
+    Some(lines.join("\n"))
+  }
+}
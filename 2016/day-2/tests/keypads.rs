@@ -0,0 +1,94 @@
+//! 2016/2's keypads, built from ASCII art, and typing codes on them.
+
+use aoc_2016_day_2::{Keypad, KeypadError, TypingError, PART_ONE, PART_TWO};
+use aoc_core::random::Rng;
+use aoc_core::{ParseError, Solution};
+
+const HEX: &str = "
+0123
+45 7
+89AB
+CDEF
+";
+
+/// Each line of moves `keypad` types `code` with, and how many lines tie with it.
+fn typing(keypad: &Keypad, code: &str) -> Vec<(String, u64)> {
+    keypad.typing(code).unwrap().iter().map(|t| (t.to_string(), t.ties)).collect()
+}
+
+fn code(mut keypad: Keypad, moves: &str) -> String {
+    keypad.code(&Keypad::parse(moves).unwrap())
+}
+
+#[test]
+fn built_in_keypads() {
+    let moves = "ULL\nRRDDD\nLURDL\nUUUUD";
+
+    assert_eq!(code(Keypad::from_art(PART_ONE, '5').unwrap(), moves), "1985");
+    assert_eq!(code(Keypad::from_art(PART_TWO, '5').unwrap(), moves), "5DB3");
+}
+
+#[test]
+fn phone_keypad() {
+    let phone = "
+123
+456
+789
+*0#
+";
+
+    assert_eq!(code(Keypad::from_art(phone, '0').unwrap(), "U\nLDD\nRR\nUUUU"), "8*#3");
+}
+
+#[test]
+fn hex_keypad_with_gaps() {
+    // Right from 5 is the gap, so it stays put.
+    assert_eq!(code(Keypad::from_art(HEX, '5').unwrap(), "R\nDR\nLUU"), "5A1");
+}
+
+#[test]
+fn bad_keypads() {
+    assert_eq!(
+        Keypad::from_art("\n123\n4 1\n", '2').err(),
+        Some(KeypadError::Art(ParseError::new(2, 3, "1", "a label no other button has")))
+    );
+    assert_eq!(Keypad::from_art(PART_ONE, 'A').err(), Some(KeypadError::NoStart('A')));
+    assert_eq!(KeypadError::NoStart('A').to_string(), "there's no A button to start on");
+}
+
+#[test]
+fn typing_takes_the_shortest_lines() {
+    let lines = |pairs: &[(&str, u64)]| -> Vec<(String, u64)> { pairs.iter().map(|&(l, t)| (l.to_string(), t)).collect() };
+
+    assert_eq!(typing(&Keypad::new_part_one(), "1985"), lines(&[("UL", 2), ("RRDD", 6), ("L", 1), ("U", 1)]));
+    assert_eq!(typing(&Keypad::new_part_two(), "95D"), lines(&[("RRRR", 1), ("LLLL", 1), ("RRDD", 2)]));
+    assert_eq!(typing(&Keypad::new_part_one(), "55"), lines(&[("", 1), ("", 1)]));
+}
+
+#[test]
+fn typing_round_trips() {
+    let mut rng = Rng::new(2016);
+    let keypads = [(PART_ONE, "123456789"), (PART_TWO, "123456789ABCD"), (HEX, "012345789ABCDEF")];
+
+    for (art, buttons) in keypads {
+        let buttons: Vec<char> = buttons.chars().collect();
+
+        for _ in 0..50 {
+            let code: String = (0..rng.range(1..=10)).map(|_| *rng.pick(&buttons)).collect();
+            let typed = Keypad::from_art(art, '5').unwrap().typing(&code).unwrap();
+            let moves: Vec<_> = typed.into_iter().map(|t| t.moves).collect();
+
+            assert_eq!(Keypad::from_art(art, '5').unwrap().code(&moves), code);
+        }
+    }
+}
+
+#[test]
+fn typing_flags_buttons_it_cant_reach() {
+    let islands = Keypad::from_art("12 3\n4   ", '1').unwrap();
+
+    assert_eq!(islands.typing("42").map(|t| t.len()), Ok(2));
+    assert_eq!(islands.typing("43").err(), Some(TypingError::Unreachable { from: '4', to: '3' }));
+    assert_eq!(islands.typing("1X").err(), Some(TypingError::NoSuchButton('X')));
+    assert_eq!(TypingError::Unreachable { from: '4', to: '3' }.to_string(), "there's no way from 4 to 3");
+}
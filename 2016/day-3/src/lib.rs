@@ -0,0 +1,78 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+fn split(s: &str) -> Result<[i32;3], ParseError> {
+  let vec:Vec<i32> = s.split_whitespace()
+    .map(|x| x.parse::<i32>().map_err(|_| ParseError::within(s, x, "a side length")))
+    .collect::<Result<Vec<i32>, ParseError>>()?;
+
+  match vec[..] {
+    [a, b, c] => Ok([a, b, c]),
+    _         => Err(ParseError::within(s, s.trim(), "three side lengths")),
+  }
+}
+
+#[derive(Debug)]
+pub struct Triangle { a: i32, b: i32, c: i32 }
+impl Triangle {
+  fn new(sides: &[i32;3]) -> Triangle {
+    Triangle { a: sides[0], b: sides[1], c: sides[2] }
+  }
+
+  fn is_valid(&self) -> bool {
+    // Two big sides add up to more than an i32 holds.
+    let (a, b, c) = (self.a as i64, self.b as i64, self.c as i64);
+
+    (a + b > c) &&
+    (b + c > a) &&
+    (c + a > b)
+  }
+}
+
+fn part_one(rows: &[[i32;3]]) -> Answer {
+  let triangles: Vec<Triangle> = rows.iter().map(Triangle::new).collect();
+  let valid_triangles: Vec<&Triangle> = triangles.iter().filter(|t| (*t).is_valid()).collect();
+
+  let num_triangles = triangles.len();
+  let num_valid_triangles = valid_triangles.len();
+  Answer::from(num_valid_triangles).with_detail(format!("{}/{} Valid Triangles", num_valid_triangles, num_triangles))
+}
+
+fn part_two(all_rows: &[[i32;3]]) -> Answer {
+  let num_lines = all_rows.len();
+  let mut triangles: Vec<Triangle> = Vec::with_capacity(num_lines);
+
+  for i in 0..(num_lines / 3) {
+    let base = i * 3;
+    let rows = [all_rows[base], all_rows[base + 1], all_rows[base + 2]]; 
+    for ((&a, &b), &c) in rows[0].iter().zip(rows[1].iter()).zip(rows[2].iter()) {
+      triangles.push(Triangle { a, b, c })
+    }
+  }
+
+  let valid_triangles: Vec<&Triangle> = triangles.iter().filter(|t| (*t).is_valid()).collect();
+
+  let num_triangles = triangles.len();
+  let num_valid_triangles = valid_triangles.len();
+  Answer::from(num_valid_triangles).with_detail(format!("{}/{} Valid Triangles", num_valid_triangles, num_triangles))
+}
+
+impl Solution for Triangle {
+  type Input = Vec<[i32;3]>;
+
+  fn parse(input: &str) -> Result<Vec<[i32;3]>, ParseError> {
+    aoc_core::parse_lines(input, split)
+  }
+
+  fn part1(rows: &Vec<[i32;3]>) -> Answer { part_one(rows) }
+  fn part2(rows: &Vec<[i32;3]>) -> Answer { part_two(rows) }
+
+  /// `size` rows of side lengths, rounded up to a multiple of three for part 2's columns.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let rows = size.unwrap_or(1800).max(1).div_ceil(3) * 3;
+    let lines:Vec<String> = (0..rows)
+      .map(|_| format!("{:>5}{:>5}{:>5}", rng.range(1..=999), rng.range(1..=999), rng.range(1..=999)))
+      .collect();
+
+    Some(lines.join("\n"))
+  }
+}
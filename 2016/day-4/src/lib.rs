@@ -0,0 +1,163 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use aoc_parse::Scanner;
+
+use std::fmt;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// One or more lowercase letters, the only thing names and checksums are made of.
+fn letters<'a>(s: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+  match s.take_while(|c| c.is_ascii_lowercase()) {
+    ""      => Err(s.error("lowercase letters")),
+    letters => Ok(letters),
+  }
+}
+
+#[derive(Debug)]
+pub struct Room { name: String, sector_id: i32, checksum: String }
+
+impl Room {
+  /// Construct a `Room` from a string like "aaaaa-bbb-z-y-x-123[abxyz]".
+  fn parse(s: &str) -> Result<Room, ParseError> {
+    aoc_parse::parse(s.trim(), |s| {
+      let name = s.expecting("an encrypted name", |s| s.separated("-", letters))?;
+      s.literal("-")?;
+      let sector_id:i32 = s.expecting("a sector ID", Scanner::unsigned)?;
+      let checksum = s.bracketed("[", "]", |s| s.expecting("a checksum", letters))?;
+
+      Ok(Room { name: name.join("-"), sector_id, checksum: checksum.to_string() })
+    })
+  }
+
+  /// Returns true if the room + checksum line up.
+  ///
+  /// A room is real (not a decoy) if the checksum is the five most common letters in the encrypted 
+  /// name, in order, with ties broken by alphabetization.
+  fn is_real(&self) -> bool {
+    self.checksum == self.computed_checksum()
+  }
+
+  /// Decrypted room name
+  ///
+  /// To decrypt a room name, rotate each letter forward through the alphabet a number of times 
+  /// equal to the room's sector ID.
+  fn decrypted_name(&self) -> String {
+    fn rotate(c:char, steps:u32) -> char {
+      if c == '-' { return ' '; }
+
+      let base_i          = b'a';
+      let char_i          = c as u8;
+      let small_steps     = (steps % 26) as u8;
+      let rotated_char_i  = (((char_i - base_i) + small_steps) % 26) + base_i;
+
+      rotated_char_i as char
+    }
+
+    let rotated_room_name = self.name.chars().map(|c| rotate(c, self.sector_id as u32)).collect();
+
+    rotated_room_name 
+  }
+
+
+
+  /// Compute the checksum for the room.
+  fn computed_checksum(&self) -> String {
+    // Get hash
+    let cc = self.char_counts();
+
+    // Build vec
+    let mut occ:Vec<(char, i32)> = Vec::with_capacity(cc.len());
+    for (chr, count) in cc { occ.push((chr, count)); }
+
+    // Sort vec by alpha.
+    occ.sort_by_key(|&(a_chr, _)| a_chr);
+
+    // Sort vec by count.
+    occ.sort_by_key(|&(_, count)| Reverse(count));
+
+    let mut chrs:Vec<char> = occ.iter().map(|&(a_chr, _)| a_chr).collect();
+    chrs.truncate(5);
+
+    chrs.into_iter().collect()
+  }
+
+  /// Hash of char -> count.
+  fn char_counts(&self) -> HashMap<char, i32> {
+    let mut cc:HashMap<char, i32> = HashMap::new();
+
+    for c in self.name.chars() {
+      if c == '-' { continue; }
+      let count = cc.entry(c).or_insert(0);
+      *count += 1;
+    }
+
+    cc
+  }
+}
+
+impl fmt::Display for Room {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { 
+    write!(
+      f,
+      "{}\n\tSID {}\n\tCKS {}\n\tACK {}\n\tCK? {}\n", 
+      self.name, 
+      self.sector_id, 
+      self.checksum,
+      self.computed_checksum(),
+      if self.is_real() { "real" } else { "fake" }
+    )
+  }
+}
+
+impl Solution for Room {
+  type Input = Vec<Room>;
+
+  fn parse(input: &str) -> Result<Vec<Room>, ParseError> {
+    aoc_core::parse_lines(input, Room::parse)
+  }
+
+  fn part1(rooms: &Vec<Room>) -> Answer {
+    let real_rooms:Vec<&Room> = rooms.iter().filter(|room| room.is_real()).collect();
+    let pt1:i64 = real_rooms.iter().fold(0, |sum, room| sum + room.sector_id as i64);
+    Answer::from(pt1).with_detail(format!("{}/{} real rooms", real_rooms.len(), rooms.len()))
+  }
+
+  fn part2(rooms: &Vec<Room>) -> Answer {
+    // for room in rooms { println!("{} -> {}", room.name, room.decrypted_name()); }
+    // This is hacky, but whatever.
+    // The magic string is "northpole object storage".
+
+    match rooms.iter().find(|room| room.decrypted_name() == "northpole object storage") {
+      Some(northpole_room) => northpole_room.sector_id.into(),
+      None                 => Answer::from("none").with_detail("No North Pole object storage room"),
+    }
+  }
+
+  /// `size` rooms, about half of them real, and one of them the North Pole object storage.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let count = size.unwrap_or(1000).max(1);
+    let storage = rng.below(count);
+
+    let lines:Vec<String> = (0..count).map(|i| {
+      let sector_id = rng.range(100..=999) as i32;
+      let name = if i == storage {
+        // Rotate backwards, so that decrypting brings it back.
+        let back = (26 - sector_id % 26) as u32;
+        Room { name: "northpole-object-storage".to_string(), sector_id: back as i32, checksum: String::new() }
+          .decrypted_name()
+          .replace(' ', "-")
+      } else {
+        let words:Vec<String> = (0..rng.range(2..=5)).map(|_| { let len = rng.range(3..=10); rng.letters(len as usize) }).collect();
+        words.join("-")
+      };
+
+      let room = Room { name, sector_id, checksum: String::new() };
+      let checksum = if i == storage || rng.one_in(2) { room.computed_checksum() } else { rng.letters(5) };
+
+      format!("{}-{}[{}]", room.name, sector_id, checksum)
+    }).collect();
+
+    Some(lines.join("\n"))
+  }
+}
@@ -0,0 +1,146 @@
+use aoc_core::{random::Rng, viz, Answer, ParseError, Solution};
+
+use md5::{Digest, Md5};
+
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+fn check_first_five(hash:&[u8]) -> bool {
+  // Nice little trick
+  // https://gist.github.com/gkbrk/2e4835e3a17b3fb6e1e7
+  let first_five = hash[0] as i32 + hash[1] as i32 + (hash[2] >> 4) as i32;
+  first_five == 0
+}
+
+fn cool_hacker_s(pw:&[i32;8], pi:usize, pv:u8) -> String {
+  let mut s = String::new();
+
+  for (i, &c) in pw.iter().enumerate() { 
+
+    if pi == i {
+      s.push_str(format!("{:x}", pv).as_ref());
+    } else {
+      if c == -1 {
+        s.push('_');
+      } else {
+        s.push_str(format!("{:x}", c).as_ref());
+      }
+    }
+  }
+
+  s
+}
+
+/// What's been cracked so far. While still hacking, `trying` is the hash's 6th and 7th characters,
+/// flickering through the passwords as if they're being tried.
+fn hacker_frame(key:&str, idx:&str, pt1:&str, pt2:&[i32;8], trying:Option<(u8, u8)>) -> String {
+  let mut pt1 = pt1.to_string();
+  let pt2 = match trying {
+    Some((sixth_char, seventh_char)) => {
+      if pt1.len() < 8 { write!(&mut pt1, "{:x}", sixth_char).unwrap(); }
+      cool_hacker_s(pt2, sixth_char as usize, seventh_char)
+    },
+    None => cool_hacker_s(pt2, pt2.len(), 0),
+  };
+
+  format!(
+    "\n   santops> H4XING PT 1 {}{} {}\n   santops> H4XING PT 2 {}{} {}",
+    key, idx, pt1,
+    key, idx, pt2
+  )
+}
+
+fn pt1_password_complete(p:&str)      -> bool { p.len() >= 8 }
+fn pt2_password_complete(a:&[i32;8])  -> bool { for c in a { if *c == -1 { return false } }; true }
+
+/// Hack away at the door until we've got both passwords. They come from the same hashes, so
+/// there's no point hashing twice.
+fn hack(key:&str) -> (String, String) {
+  let mut md5 = Md5::new();
+  let mut indices = 0..;
+
+  let mut pt1_password = String::new();
+  let mut pt2_password:[i32;8] = [-1; 8];
+
+  let animate = viz::active();
+
+  while !pt1_password_complete(&pt1_password) || !pt2_password_complete(&pt2_password) {
+    let idx = indices.next().unwrap();
+
+    md5.update(key.as_bytes());
+    md5.update(idx.to_string().as_bytes());
+    let hash = md5.finalize_reset();
+
+    // Bitwise AND out the 5th char, write the 6th out to password.
+    let sixth_char    = hash[2] & 0x0F;
+    // Bitwise downshift the 7th hex to the 8th
+    let seventh_char  = hash[3] >> 4;
+
+    if check_first_five(&hash) {
+      if !pt1_password_complete(&pt1_password) {
+        // Grab the 6th character in the hash.
+        write!(&mut pt1_password, "{:x}", sixth_char).unwrap();
+      }
+
+      if !pt2_password_complete(&pt2_password) {
+        // 6th character determines location (zero-based index).
+        // 7th character determines contents.
+        if sixth_char < 8 && pt2_password[sixth_char as usize] == -1 {
+          pt2_password[sixth_char as usize] = seventh_char as i32;
+        }
+      }
+    }
+
+    // Animation
+    if animate && viz::due() {
+      let frame = hacker_frame(key, &idx.to_string(), &pt1_password, &pt2_password, Some((sixth_char, seventh_char)));
+      viz::draw(&frame);
+    }
+  }
+
+  if animate {
+    viz::draw(&hacker_frame(key, "", &pt1_password, &pt2_password, None));
+    viz::finish();
+  }
+
+  let mut pt2_out = String::new();
+  for c in &pt2_password { write!(&mut pt2_out, "{:x}", c).unwrap(); }
+
+  (pt1_password, pt2_out)
+}
+
+/// How About a Nice Game of Chess?
+pub struct Door;
+
+/// The door ID, and both passwords once whichever part asks first has hacked them.
+pub struct DoorId { key: String, passwords: OnceLock<(String, String)> }
+
+impl DoorId {
+  fn passwords(&self) -> &(String, String) {
+    self.passwords.get_or_init(|| hack(&self.key))
+  }
+}
+
+impl Solution for Door {
+  type Input = DoorId;
+
+  fn parse(input: &str) -> Result<DoorId, ParseError> {
+    Ok(DoorId { key: input.trim().to_string(), passwords: OnceLock::new() })
+  }
+
+  fn part1(door: &DoorId) -> Answer {
+    let (pt1_password, _) = door.passwords();
+    pt1_password.as_str().into()
+  }
+
+  fn part2(door: &DoorId) -> Answer {
+    let (_, pt2_password) = door.passwords();
+    pt2_password.as_str().into()
+  }
+
+  /// An eight letter door ID. `size` doesn't change anything; the hashing takes as long as it
+  /// takes.
+  fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+    Some(rng.letters(8))
+  }
+}
@@ -0,0 +1,96 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::str;
+
+use std::collections::HashMap;
+
+fn build_cols(lines:&[&str]) -> Vec<String> {
+  let num_cols = lines.first().map_or(0, |l| l.chars().count());
+  let mut cols:Vec<String> = Vec::with_capacity(num_cols);
+  for _ in 0..num_cols { cols.push(String::new()); }
+
+  for line in lines {
+    for (i, c) in line.chars().enumerate() { cols[i].push(c) }
+  }
+
+  cols
+}
+
+fn most_frequent_char(s:&str) -> char {
+  let counts:HashMap<char, u32> = s.chars()
+    .fold(HashMap::new(), |mut counts, c| { *(counts.entry(c).or_insert(0)) += 1; counts })
+  ;
+
+  let mfc:(char, u32) = counts.iter().fold((' ', 0), |cur, chr| if cur.1 > *chr.1 { cur } else { (*chr.0, *chr.1) });
+
+  mfc.0
+}
+
+fn least_frequent_char(s:&str) -> char {
+  let counts:HashMap<char, u32> = s.chars()
+    .fold(HashMap::new(), |mut counts, c| { *(counts.entry(c).or_insert(0)) += 1; counts })
+  ;
+
+  let lfc:(char, u32) = counts.iter().fold((' ', 1000), |cur, chr| if cur.1 < *chr.1 { cur } else { (*chr.0, *chr.1) });
+
+  lfc.0
+}
+
+
+/// Signals and Noise
+pub struct Signal;
+
+impl Solution for Signal {
+  /// The repeated message, one String per column.
+  type Input = Vec<String>;
+
+  fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines:Vec<&str> = aoc_core::lines(input).into_iter().map(str::trim).collect();
+    // for (i, line) in lines.iter().enumerate() { println!("{:03}: {}", i, line); }
+
+    // Every copy of the message is the same length, or the columns don't line up.
+    let width = lines.first().map_or(0, |l| l.chars().count());
+    aoc_core::parse_lines(input, |line| {
+      let line = line.trim();
+      if line.chars().count() == width { Ok(()) }
+      else { Err(ParseError::within(line, line, format!("a message {} characters long, like the first", width))) }
+    })?;
+
+    Ok(build_cols(&lines))
+  }
+
+  fn part1(cols: &Vec<String>) -> Answer {
+    // for col in cols { println!("{}", col); }
+    let pt1_code:String = cols.iter().map(|col| most_frequent_char(col)).collect();
+    pt1_code.into()
+  }
+
+  fn part2(cols: &Vec<String>) -> Answer {
+    let pt2_code:String = cols.iter().map(|col| least_frequent_char(col)).collect();
+    pt2_code.into()
+  }
+
+  /// `size` copies of an eight letter message. Each column has one letter that turns up far more
+  /// than the rest and one that only turns up once, so both parts have a single answer.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let count = size.unwrap_or(600).max(1);
+    let columns:Vec<(char, char, usize)> = (0..8).map(|_| {
+      let mut letters:Vec<char> = ('a'..='z').collect();
+      rng.shuffle(&mut letters);
+      (letters[0], letters[1], rng.below(count))
+    }).collect();
+
+    let lines:Vec<String> = (0..count).map(|i| {
+      columns.iter().map(|&(common, rare, rare_row)| {
+        if i == rare_row { return rare; }
+        if rng.one_in(3) { return common; }
+        loop {
+          let c = (b'a' + rng.below(26) as u8) as char;
+          if c != common && c != rare { return c; }
+        }
+      }).collect()
+    }).collect();
+
+    Some(lines.join("\n"))
+  }
+}
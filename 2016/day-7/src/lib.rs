@@ -1,5 +1,6 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
 use std::str;
-use std::io::{self, Read};
 use std::collections::VecDeque;
 
 /// Starting indices of all abbas inside s.
@@ -60,11 +61,12 @@ fn abas(s:&str) -> Vec<(usize, String)> {
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 /// Bunny Network IP. 
 /// `address`   : the actual address
 /// `hypernets` : the network sequences inside [] brackets
 /// `supernets` : the network sequences outside [] brackets
-struct IP { address: String, supernets: Vec<String>, hypernets: Vec<String> }
+pub struct IP { address: String, supernets: Vec<String>, hypernets: Vec<String> }
 
 impl IP {
   fn new(address:&str) -> IP {
@@ -102,7 +104,7 @@ impl IP {
     if !supernet.is_empty() { supernets.push(supernet.clone()); }
     if !hypernet.is_empty() { hypernets.push(hypernet.clone()); }
 
-    IP { address: address.to_string(), supernets: supernets, hypernets: hypernets }
+    IP { address: address.to_string(), supernets, hypernets }
   }
 
   /// An address is TLS if it contains at least 1 abba in the supernets and 0 abbas in the hypernets.
@@ -114,31 +116,57 @@ impl IP {
   /// An address is SSL if it contains 1 ABA in the supernets _and_ corresponding BAB in hypernets.
   fn is_ssl(&self) -> bool {
     self.supernets.iter().any(|supernet| {
-      abas(&supernet).iter().any(|&(_, ref aba)| {
+      abas(supernet).iter().any(|(_, aba)| {
         let mut aba_chars = aba.chars();
         
         let a:char = aba_chars.next().unwrap();
         let b:char = aba_chars.next().unwrap();
         let bab:String = format!("{}{}{}", b, a, b);
 
-        self.hypernets.iter().any(|&ref s| s.contains(&bab))
+        self.hypernets.iter().any(|s| s.contains(&bab))
       })
     })
   }
 }
 
-fn main() {
-  let mut input = String::new();
-  let mut stdin = io::stdin();
-  if let Err(why) = stdin.read_to_string(&mut input) { panic!("Could not read STDIN: {}", why); }
+impl Solution for IP {
+  type Input = Vec<IP>;
+
+  fn parse(input: &str) -> Result<Vec<IP>, ParseError> {
+    Ok(aoc_core::lines(input).iter().map(|line| IP::new(line)).collect())
+  }
 
-  let s = input.trim().clone();
-  let lines:Vec<&str> = s.split("\n").collect();
-  let ips:Vec<IP> = lines.iter().map(|line| IP::new(line)).collect();
+  fn part1(ips: &Vec<IP>) -> Answer {
+    let tls_ips:Vec<&IP> = ips.iter().filter(|ip| ip.is_tls()).collect();
+    tls_ips.len().into()
+  }
 
-  let tls_ips:Vec<&IP> = ips.iter().filter(|ip| ip.is_tls()).collect();
-  println!("Pt 1: {}", tls_ips.len());
+  fn part2(ips: &Vec<IP>) -> Answer {
+    let ssl_ips:Vec<&IP> = ips.iter().filter(|ip| ip.is_ssl()).collect();
+    ssl_ips.len().into()
+  }
 
-  let ssl_ips:Vec<&IP> = ips.iter().filter(|ip| ip.is_ssl()).collect();
-  println!("Pt 2: {}", ssl_ips.len());
+  /// `size` addresses, with ABBAs and ABAs sprinkled in and out of the brackets.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    fn sequence(rng: &mut Rng) -> String {
+      let len = rng.range(5..=15) as usize;
+      let mut s = rng.letters(len);
+      let (a, b) = (rng.letters(1), rng.letters(1));
+      if a != b {
+        let pattern = if rng.one_in(2) { format!("{}{}{}{}", a, b, b, a) } else { format!("{}{}{}", a, b, a) };
+        if rng.one_in(3) { let at = rng.below(s.len() + 1); s.insert_str(at, &pattern); }
+      }
+      s
+    }
+
+    let lines:Vec<String> = (0..size.unwrap_or(2000).max(1)).map(|_| {
+      let mut ip = sequence(rng);
+      for _ in 0..rng.range(1..=3) {
+        ip.push_str(&format!("[{}]{}", sequence(rng), sequence(rng)));
+      }
+      ip
+    }).collect();
+
+    Some(lines.join("\n"))
+  }
 }
@@ -0,0 +1,208 @@
+use aoc_core::{random::Rng, viz, Answer, ParseError, Solution};
+
+use std::fmt;
+
+use grid::Grid;
+
+use aoc_parse::Scanner;
+
+use std::time::Duration;
+
+const WIDTH:u32 = 50;
+const HEIGHT:u32 = 6;
+
+pub struct Screen {
+  pixels: Grid<bool>,
+}
+
+impl fmt::Display for Screen {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { 
+    fn _border(f: &mut fmt::Formatter, w:u32) -> fmt::Result {
+      write!(f, "+")?;
+      let hb:String = (0..w).map(|_| '-').collect();
+      write!(f, "{}", hb)?;
+      write!(f, "+")?;
+      writeln!(f)
+    }
+
+    let w = self.pixels.width() as u32;
+    _border(f, w)?;
+
+    let art = self.pixels.render(|pixel| if pixel == Some(&true) { '#' } else { '.' });
+    for row in art.lines() {
+      writeln!(f, "|{}|", row)?;
+    }
+
+    _border(f, w)
+  }
+}
+
+impl Screen {
+  fn new(w:u32, h:u32) -> Screen {
+    Screen { pixels: Grid::filled(w as usize, h as usize, false) }
+  }
+
+  fn execute(&mut self, op:&Operation) -> Result<(), &'static str> {
+    match *op {
+      Operation::Rect { x, y }        => self.rect(x, y),
+      Operation::RotateRow { y, num } => self.rotate_row(y, num),
+      Operation::RotateCol { x, num } => self.rotate_col(x, num),
+    }
+  }
+
+  /// Returns the count of lit pixels.
+  fn lit_pixel_count(&self) -> u32 { self.pixels.count(|&p| p) as u32 }
+
+  /// Draw a rectangle.
+  fn rect(&mut self, x:u32, y:u32) -> Result<(), &'static str> {
+    for yi in 0..y {
+      for xi in 0..x {
+        if !self.pixels.contains((xi as usize, yi as usize)) { return Err("Rectangle is bigger than the screen"); }
+        self.pixels.set((xi as usize, yi as usize), true);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Rotate a row
+  fn rotate_row(&mut self, y:u32, num:u32) -> Result<(), &'static str> {
+    if y as usize >= self.pixels.height() { return Err("No such row"); }
+    self.pixels.rotate_row(y as usize, num as usize);
+    Ok(())
+  }
+
+  /// Rotate a col
+  fn rotate_col(&mut self, x:u32, num:u32) -> Result<(), &'static str> {
+    if x as usize >= self.pixels.width() { return Err("No such column"); }
+    self.pixels.rotate_col(x as usize, num as usize);
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+  Rect      {   x: u32,   y: u32 },
+  RotateRow {   y: u32, num: u32 },
+  RotateCol {   x: u32, num: u32 },
+}
+
+impl fmt::Display for Operation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { 
+    match *self {
+      Operation::Rect { x, y }        => write!(f, "rect {}x{}", x, y),
+      Operation::RotateRow { y, num } => write!(f, "rotate row y={} by {}", y, num),
+      Operation::RotateCol { x, num } => write!(f, "rotate column x={} by {}", x, num),
+    }
+  }  
+}
+
+impl Operation {
+  /// Parse "rect 3x2", "rotate row y=0 by 4" or "rotate column x=1 by 1".
+  pub fn parse(s:&str) -> Result<Operation, ParseError> {
+    fn _parse_rect(s:&mut Scanner) -> Result<Operation, ParseError> {
+      // "rect 1x2"
+      let x = s.expecting("a width", Scanner::unsigned)?;
+      s.literal("x")?;
+      Ok(Operation::Rect { x, y: s.expecting("a height", Scanner::unsigned)? })
+    }
+
+    fn _parse_rotation(s:&mut Scanner, what:&str) -> Result<(u32, u32), ParseError> {
+      // "0 by 2", after "rotate row y="
+      let n = s.expecting(what, Scanner::unsigned)?;
+      s.literal(" by ")?;
+      Ok((n, s.expecting("an amount", Scanner::unsigned)?))
+    }
+
+    aoc_parse::parse(s.trim(), |s| {
+      if s.optional(|s| s.literal("rect ")).is_some() { return _parse_rect(s); }
+      if s.optional(|s| s.literal("rotate row y=")).is_some() {
+        let (y, num) = _parse_rotation(s, "a row")?;
+        return Ok(Operation::RotateRow { y, num });
+      }
+      if s.optional(|s| s.literal("rotate column x=")).is_some() {
+        let (x, num) = _parse_rotation(s, "a column")?;
+        return Ok(Operation::RotateCol { x, num });
+      }
+
+      Err(s.error("rect, rotate row y= or rotate column x="))
+    })
+  }
+
+  /// Whether the operation stays on a `w` by `h` screen.
+  fn fits(&self, w:u32, h:u32) -> bool {
+    match *self {
+      Operation::Rect { x, y }      => x <= w && y <= h,
+      Operation::RotateRow { y, .. } => y < h,
+      Operation::RotateCol { x, .. } => x < w,
+    }
+  }
+}
+
+fn draw_screen(screen:& Screen) {
+  viz::draw(&screen.to_string());
+  viz::pause(Duration::from_millis(12));
+}
+
+fn animated_ops(screen:&mut Screen, operations:&Vec<Operation>) {
+  for op in operations { 
+    // Explode each operation into 1 cell
+    match *op {
+      Operation::Rect { .. }          => { screen.execute(op).unwrap(); draw_screen(screen); },
+      Operation::RotateRow { y, num } => {
+        for _ in 0..num {
+          let op = Operation::RotateRow { y, num: 1 };
+          screen.execute(&op).unwrap();
+          draw_screen(screen);
+        }
+      },
+      Operation::RotateCol { x, num } => {
+        for _ in 0..num {
+          let op = Operation::RotateCol { x, num: 1 };
+          screen.execute(&op).unwrap();
+          draw_screen(screen);
+        }
+      },
+    }
+  }
+  viz::pause(Duration::from_millis(2000));
+  viz::finish();
+}
+
+impl Solution for Screen {
+  /// The screen after every operation has run.
+  type Input = Screen;
+
+  fn parse(input: &str) -> Result<Screen, ParseError> {
+    let operations:Vec<Operation> = aoc_core::parse_lines(input, |line| {
+      let op = Operation::parse(line)?;
+      if op.fits(WIDTH, HEIGHT) { Ok(op) }
+      else { Err(ParseError::within(line, line.trim(), format!("an operation that fits the {}x{} screen", WIDTH, HEIGHT))) }
+    })?;
+    let mut screen:Screen = Screen::new(WIDTH, HEIGHT);
+    let animate = viz::active();
+
+    if animate  { animated_ops(&mut screen, &operations); }
+    else        { for op in &operations { screen.execute(op).unwrap(); } }
+
+    Ok(screen)
+  }
+
+  fn part1(screen: &Screen) -> Answer { screen.lit_pixel_count().into() }
+  fn part2(screen: &Screen) -> Answer { screen.to_string().into() }
+
+  /// `size` operations that all fit the 50x6 screen.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    let lines:Vec<String> = (0..size.unwrap_or(160).max(1)).map(|_| {
+      let mut n = |range| rng.range(range) as u32;
+      let op = match n(0..=2) {
+        0 => Operation::Rect { x: n(1..=10), y: n(1..=3) },
+        1 => Operation::RotateRow { y: n(0..=5), num: n(1..=49) },
+        _ => Operation::RotateCol { x: n(0..=49), num: n(1..=5) },
+      };
+      op.to_string()
+    }).collect();
+
+    Some(lines.join("\n"))
+  }
+}
@@ -0,0 +1,150 @@
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::fmt;
+
+use aoc_parse::Scanner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker { num_chars: usize, repeats: u32 }
+
+impl fmt::Display for Marker {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { 
+    write!(f, "({}x{})", self.num_chars, self.repeats) 
+  }  
+}
+
+impl Marker {
+  /// Parse a lone marker, "(10x2)".
+  pub fn parse(s:&str) -> Result<Marker, ParseError> { aoc_parse::parse(s, scan_marker) }
+
+  /// How long `inner_length` characters come out once repeated, if that fits in a `u64`.
+  fn repeat(&self, inner_length:u64) -> Option<u64> { inner_length.checked_mul(self.repeats as u64) }
+}
+
+/*
+  The format compresses a sequence of characters. Whitespace is ignored. 
+  
+  To indicate that some sequence should be repeated, a marker is added to the file, 
+  like (10x2). 
+  
+  To decompress this marker, take the subsequent 10 characters and repeat them 2
+  times. Then, continue reading the file after the repeated data. 
+  
+  The marker itself is not included in the decompressed output.
+
+  If parentheses or other characters appear within the data referenced by a marker, 
+  that's okay - treat it like normal data, not a marker, and then resume looking for markers 
+  after the decompressed section.
+*/
+fn scan_marker(s:&mut Scanner) -> Result<Marker, ParseError> {
+  // Marker will match /\((\d+)x(\d+)\)/
+  s.bracketed("(", ")", |s| {
+    let num_chars = s.expecting("a number of characters", Scanner::unsigned)?;
+    s.literal("x")?;
+    Ok(Marker { num_chars, repeats: s.expecting("a number of repeats", Scanner::unsigned)? })
+  })
+}
+
+/// Split `s` into markers and the text each one repeats. Text outside any marker is treated as
+/// repeated once.
+fn split(s:&str) -> Result<Vec<(Marker, &str)>, ParseError> {
+  aoc_parse::parse(s, |s| {
+    let mut ms:Vec<(Marker, &str)> = Vec::new();
+
+    while let Some(c) = s.peek() {
+      let nms = if c == '(' {
+        let marker = scan_marker(s)?;
+        let repeated = s.take(marker.num_chars)?;
+        (marker, repeated)
+      } else {
+        let simple = s.take_while(|c| c != '(');
+        (Marker { num_chars: simple.chars().count(), repeats: 1 }, simple)
+      };
+
+      ms.push(nms);
+    }
+
+    Ok(ms)
+  })
+}
+
+/// Make sure every marker is well formed, including the ones inside repeated text.
+fn check(s:&str) -> Result<(), ParseError> {
+  for (_, repeated) in split(s)? {
+    if repeated.contains('(') { check(repeated).map_err(|e| e.in_context(s, repeated))?; }
+  }
+
+  Ok(())
+}
+
+/// compute the decompressed length of a string, don't expand markers. `None` if it's too long
+/// to count in a `u64`.
+/// `s` : str to compute
+fn cdl(s:&str) -> Option<u64> {
+  split(s)
+    .expect("checked by parse")
+    .iter()
+    .try_fold(0u64, |a, (m, _)| a.checked_add(m.repeat(m.num_chars as u64)?))
+}
+
+/// compute the decompressed length of a string, do expand markers. `None` if it's too long to
+/// count in a `u64`.
+/// `s` : str to compute
+fn cdl2(s:&str) -> Option<u64> {
+  split(s)
+    .expect("checked by parse")
+    .iter()
+    .try_fold(0u64, |a, (m, s)| {
+      let sl = if s.contains('(') { m.repeat(cdl2(s)?)? }
+               else               { m.repeat(m.num_chars as u64)? }
+      ;
+
+      a.checked_add(sl)
+    })
+}
+
+/// The length, or that it's too long to count.
+fn length_answer(length:Option<u64>) -> Answer {
+  match length {
+    Some(length) => length.into(),
+    None         => Answer::from("overflow").with_detail("Decompresses to more than u64::MAX characters"),
+  }
+}
+
+/// Explosives in Cyberspace
+pub struct Decompressor;
+
+impl Solution for Decompressor {
+  type Input = String;
+
+  fn parse(input: &str) -> Result<String, ParseError> {
+    let s = input.trim();
+    check(s)?;
+    Ok(s.to_string())
+  }
+
+  fn part1(s: &String) -> Answer { length_answer(cdl(s)) }
+  fn part2(s: &String) -> Answer { length_answer(cdl2(s)) }
+
+  /// About `size` characters, with markers nested up to four deep inside each other's data.
+  fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+    fn compressed(rng: &mut Rng, len: usize, depth: u32) -> String {
+      let mut s = String::new();
+
+      while s.len() < len {
+        if depth < 4 && rng.one_in(2) {
+          let inner_len = rng.range(1..=40) as usize;
+          let inner = compressed(rng, inner_len, depth + 1);
+          s.push_str(&format!("({}x{}){}", inner.len(), rng.range(2..=12), inner));
+        } else {
+          let plain = rng.range(1..=10) as usize;
+          s.push_str(&rng.letters(plain).to_uppercase());
+        }
+      }
+
+      s
+    }
+
+    Some(compressed(rng, size.unwrap_or(10000).max(1), 0))
+  }
+}
@@ -0,0 +1,55 @@
+// http://adventofcode.com/2017/day/10
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+use knot_hash::List;
+
+/// Marks in the list part 1 twists, and so the longest length it can twist by.
+const MARKS: usize = 256;
+
+pub struct KnotHash;
+
+/// The input both ways the parts read it.
+pub struct Lengths {
+    /// Comma-separated, for part 1.
+    lengths: Vec<usize>,
+    /// As a string of bytes, for part 2.
+    text: String,
+}
+
+impl Solution for KnotHash {
+    type Input = Lengths;
+
+    fn parse(input: &str) -> Result<Lengths, ParseError> {
+        let line = input.trim();
+
+        let lengths = line.split(',')
+            .map(str::trim)
+            .map(|s| match s.parse::<usize>() {
+                Ok(length) if length <= MARKS => Ok(length),
+                _ => Err(ParseError::within(line, s, format!("a length from 0 to {}", MARKS))),
+            })
+            .collect::<Result<Vec<usize>, ParseError>>()?
+        ;
+
+        Ok(Lengths { lengths, text: line.to_string() })
+    }
+
+    fn part1(input: &Lengths) -> Answer {
+        let mut list = List::new(MARKS);
+        list.round(&input.lengths);
+        (list.get(0) * list.get(1)).into()
+    }
+
+    fn part2(input: &Lengths) -> Answer {
+        knot_hash::hex(&input.text).into()
+    }
+
+    /// `size` lengths, each no longer than the list.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let lengths: Vec<String> = (0..size.unwrap_or(16).max(1))
+            .map(|_| rng.range(0..=255).to_string())
+            .collect();
+
+        Some(lengths.join(","))
+    }
+}
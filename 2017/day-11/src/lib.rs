@@ -0,0 +1,121 @@
+// http://adventofcode.com/%YEAR%/day/%DAY%
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+use geometry::{Ordinal, Point3};
+
+use std::cmp;
+use std::fmt;
+
+/// The hex grid's six directions: every point of the compass except due east and west.
+fn parse_direction(s: &str) -> Option<Ordinal> {
+    match s {
+        "n"  => Some(Ordinal::North),
+        "ne" => Some(Ordinal::NorthEast),
+        "se" => Some(Ordinal::SouthEast),
+        "s"  => Some(Ordinal::South),
+        "sw" => Some(Ordinal::SouthWest),
+        "nw" => Some(Ordinal::NorthWest),
+        _    => None
+    }
+}
+
+/// One step in cube coordinates, where every hex's x + y + z is 0.
+fn cube_vector(direction: Ordinal) -> Point3<i32> {
+    match direction {
+        Ordinal::North     => Point3::new( 0,  1, -1),
+        Ordinal::NorthEast => Point3::new( 1,  0, -1),
+        Ordinal::SouthEast => Point3::new( 1, -1,  0),
+        Ordinal::South     => Point3::new( 0, -1,  1),
+        Ordinal::SouthWest => Point3::new(-1,  0,  1),
+        Ordinal::NorthWest => Point3::new(-1,  1,  0),
+        Ordinal::East | Ordinal::West => panic!("Hexes have no {} neighbour", direction)
+    }
+}
+
+#[derive(PartialEq)]
+#[derive(Clone)]
+pub struct HexCoordinate {
+    cube: Point3<i32>
+}
+
+impl HexCoordinate {
+    fn new() -> HexCoordinate {
+        HexCoordinate {
+            cube: Point3::origin()
+        }
+    }
+
+    /// Move 1 step in direction
+    pub fn mv(&self, direction: &Ordinal) -> HexCoordinate {
+        HexCoordinate {
+            cube: self.cube + cube_vector(*direction)
+        }
+    }
+
+    /// Number of steps between self and hc. Each step changes two of the cube coordinates by one,
+    /// so it's the biggest difference between any of them.
+    pub fn distance(&self, hc: &HexCoordinate) -> u32 {
+        self.cube.chebyshev(hc.cube) as u32
+    }
+
+    pub fn to_s(&self) -> String {
+        format!("<{:3}/{:3}>", self.cube.x, self.cube.y)
+    }
+}
+
+impl fmt::Display for HexCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_s())
+    }
+}
+
+impl Solution for HexCoordinate {
+    type Input = Vec<Ordinal>;
+
+    fn parse(input: &str) -> Result<Vec<Ordinal>, ParseError> {
+        let lines = aoc_core::parse_lines(input, |line| {
+            line.split(',')
+                .map(str::trim)
+                .map(|s| parse_direction(s).ok_or_else(|| ParseError::within(line, s, "one of n, ne, se, s, sw, nw")))
+                .collect::<Result<Vec<Ordinal>, ParseError>>()
+        })?;
+
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(moves: &Vec<Ordinal>) -> Answer {
+        let origin = HexCoordinate::new();
+        let location = moves.iter().fold(HexCoordinate::new(), |hex_c, hex_d| hex_c.mv(hex_d));
+
+        location.distance(&origin).into()
+    }
+
+    fn part2(moves: &Vec<Ordinal>) -> Answer {
+        let origin = HexCoordinate::new();
+
+        let (_, max_distance) = moves.iter().fold(
+            // (coords, longest distance)
+            (HexCoordinate::new(), 0),
+            |(hex_c, max_d), hex_d| {
+                let next_hex_c = hex_c.mv(hex_d);
+                let next_max_d = cmp::max(next_hex_c.distance(&origin), max_d);
+
+                (next_hex_c, next_max_d)
+            }
+        );
+
+        max_distance.into()
+    }
+
+    /// `size` steps, drifting one way so the child ends up somewhere.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let directions = ["n", "ne", "se", "s", "sw", "nw"];
+        let drift = *rng.pick(&directions);
+
+        let steps: Vec<&str> = (0..size.unwrap_or(8000).max(1))
+            .map(|_| if rng.one_in(3) { drift } else { *rng.pick(&directions) })
+            .collect();
+
+        Some(steps.join(","))
+    }
+}
@@ -0,0 +1,127 @@
+// http://adventofcode.com/2017/day/12
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+use disjoint_set::DisjointSet;
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Node {
+    index: usize,
+    connected_node_indices: Vec<usize>
+}
+
+impl Node {}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let node_names: Vec<String> = self.connected_node_indices.iter().map(|n| n.to_string()).collect();
+        write!(
+            f, 
+            "<Node[{}] -> [{}]>", 
+            self.index,
+            node_names.join(", ")
+        )
+    }
+}
+
+/// Parse "2 <-> 0, 3, 4", in a village of `programs` programs.
+fn parse_connection(s: &str, programs: usize) -> Result<(usize, Vec<usize>), ParseError> {
+    let s = s.trim();
+    let (lnode_str, rnodes_str) = s.split_once(" <-> ")
+        .ok_or_else(|| ParseError::within(s, s, "<program> <-> <program>, <program>, ..."))?;
+
+    let lnode = lnode_str.parse::<usize>()
+        .map_err(|_| ParseError::within(s, lnode_str, "a program ID"))?;
+
+    let rnodes: Vec<usize> = rnodes_str.split(", ")
+        .map(|n| match n.parse::<usize>() {
+            Ok(id) if id < programs => Ok(id),
+            Ok(_) => Err(ParseError::within(s, n, format!("a program ID below {}", programs))),
+            Err(_) => Err(ParseError::within(s, n, "a program ID")),
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?
+    ;
+
+    Ok((lnode, rnodes))
+}
+
+/// Every program joined up with the ones it can talk to. `parse` has checked every ID is one of
+/// `nodes`.
+fn groups(nodes: &Vec<Node>) -> DisjointSet {
+    let mut groups = DisjointSet::new(nodes.len());
+
+    for node in nodes {
+        for &cni in &node.connected_node_indices {
+            groups.union(node.index, cni);
+        }
+    }
+
+    groups
+}
+
+impl Solution for Node {
+    /// Every program in the village, indexed by ID.
+    type Input = Vec<Node>;
+
+    fn parse(input: &str) -> Result<Vec<Node>, ParseError> {
+        let mut index = 0;
+        let programs = aoc_core::lines(input).len();
+
+        if programs == 0 {
+            return Err(ParseError::new(1, 1, "", "program 0 and its pipes"));
+        }
+
+        aoc_core::parse_lines(input, |line| {
+            let (lnode_n, rnodes_n) = parse_connection(line, programs)?;
+
+            if index != lnode_n {
+                let line = line.trim();
+                let lnode_str = line.split(' ').next().unwrap_or(line);
+                return Err(ParseError::within(line, lnode_str, format!("program {}", index)));
+            }
+
+            index += 1;
+
+            Ok(Node {
+                index: lnode_n,
+                connected_node_indices: rnodes_n
+            })
+        })
+    }
+
+    fn part1(nodes: &Vec<Node>) -> Answer {
+        groups(nodes).size_of(0).into()
+    }
+
+    fn part2(nodes: &Vec<Node>) -> Answer {
+        groups(nodes).count().into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(2000).max(1);
+
+        // Pipes go both ways, so each one is listed at both ends.
+        let mut pipes: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+        for program in 0..count {
+            for _ in 0..rng.below(3) {
+                let other = rng.below(count);
+                pipes[program].insert(other);
+                pipes[other].insert(program);
+            }
+        }
+
+        let lines: Vec<String> = pipes.iter().enumerate().map(|(program, others)| {
+            // Anyone left out still has a pipe to themselves.
+            let others: Vec<String> = if others.is_empty() {
+                vec![program.to_string()]
+            } else {
+                others.iter().map(|o| o.to_string()).collect()
+            };
+            format!("{} <-> {}", program, others.join(", "))
+        }).collect();
+
+        Some(lines.join("\n"))
+    }
+}
@@ -0,0 +1,144 @@
+// http://adventofcode.com/2017/day/13
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::fmt;
+
+/// Keeps a scanner's trip up and back within a `usize`.
+const MAX_RANGE: usize = i32::MAX as usize;
+
+/// A layer of the firewall with a scanner in it. Layers without one aren't kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirewallLayer {
+    depth: usize,
+    range: usize
+}
+
+impl FirewallLayer {
+    pub fn new(depth: usize, range: usize) -> FirewallLayer {
+        FirewallLayer { depth, range }
+    }
+
+    /// How often the scanner is back at the top, where it catches whoever's passing. `None` for a
+    /// range of 0, which leaves no room for a scanner.
+    fn cycle(&self) -> Option<usize> {
+        match self.range {
+            0 => None,
+            // Nowhere to go, so it never leaves the top.
+            1 => Some(1),
+            r => Some(2 * (r - 1))
+        }
+    }
+
+    /// Whether a packet that set off `delay` picoseconds late is caught here. It gets here at
+    /// `depth + delay`, worked out modulo the cycle so that it can't overflow.
+    fn catches(&self, delay: usize) -> bool {
+        self.cycle().is_some_and(|cycle| (self.depth % cycle + delay % cycle).is_multiple_of(cycle))
+    }
+}
+
+impl fmt::Display for FirewallLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.depth, self.range)
+    }
+}
+
+/// Parse "<depth>: <range>"
+fn parse_layer(s: &str) -> Result<FirewallLayer, ParseError> {
+    let s = s.trim();
+    let (depth, range) = s.split_once(": ")
+        .ok_or_else(|| ParseError::within(s, s, "<depth>: <range>"))?;
+
+    Ok(FirewallLayer::new(
+        depth.parse::<usize>().map_err(|_| ParseError::within(s, depth, "a depth"))?,
+        range.parse::<usize>().ok()
+            .filter(|&range| range <= MAX_RANGE)
+            .ok_or_else(|| ParseError::within(s, range, format!("a range up to {}", MAX_RANGE)))?
+    ))
+}
+
+fn build_firewall(input: &str) -> Result<Vec<FirewallLayer>, ParseError> {
+    let mut next_depth = 0;
+    aoc_core::parse_lines(input, |line| {
+        let layer = parse_layer(line)?;
+        if layer.depth < next_depth {
+            let line = line.trim();
+            let depth_str = line.split(':').next().unwrap_or(line);
+            return Err(ParseError::within(line, depth_str, format!("a depth of {} or more", next_depth)));
+        }
+
+        next_depth = layer.depth.saturating_add(1);
+        Ok(layer)
+    })
+}
+
+/// How long until every scanner is back where it started, or `None` if that's too long to count.
+fn period(firewall: &[FirewallLayer]) -> Option<usize> {
+    fn gcd(a: usize, b: usize) -> usize { if b == 0 { a } else { gcd(b, a % b) } }
+
+    firewall.iter()
+        .filter_map(FirewallLayer::cycle)
+        .try_fold(1, |period: usize, cycle| (period / gcd(period, cycle)).checked_mul(cycle))
+}
+
+/// The shortest delay for an undetected run, or `None` if every delay is caught. The scanners
+/// repeat every `period`, so a run that hasn't got through by then never will.
+fn delay_for_first_undetected_run(firewall: &[FirewallLayer], period: usize) -> Option<usize> {
+    // The scanners that come round most often catch the most delays, so try them first.
+    let mut firewall = firewall.to_vec();
+    firewall.sort_by_key(|layer| layer.cycle());
+
+    (0..period).find(|&delay| !firewall.iter().any(|layer| layer.catches(delay)))
+}
+
+impl Solution for FirewallLayer {
+    /// The layers with scanners, by depth.
+    type Input = Vec<FirewallLayer>;
+
+    fn parse(input: &str) -> Result<Vec<FirewallLayer>, ParseError> {
+        build_firewall(input)
+    }
+
+    fn part1(firewall: &Vec<FirewallLayer>) -> Answer {
+        // Depths go up to usize::MAX, so the severity can be well past a u64.
+        let severity: u128 = firewall.iter()
+            .filter(|layer| layer.catches(0))
+            .map(|layer| layer.depth as u128 * layer.range as u128)
+            .sum();
+
+        severity.to_string().into()
+    }
+
+    fn part2(firewall: &Vec<FirewallLayer>) -> Answer {
+        let period = match period(firewall) {
+            Some(period) => period,
+            None => return Answer::from("none").with_detail("The scanners take too long to line up again to try every delay"),
+        };
+
+        match delay_for_first_undetected_run(firewall, period) {
+            Some(delay) => delay.into(),
+            None => Answer::from("none").with_detail("Every delay gets caught"),
+        }
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(43).max(1);
+
+        // Pick a delay that gets through first and only keep the scanners it slips past, so part
+        // 2 always has an answer (if perhaps a smaller one). Not 0, which nothing at depth 0 slips
+        // past.
+        let delay = rng.range(1..=100_000) as usize;
+
+        let mut lines = vec![];
+        let mut depth = 0;
+        while lines.len() < count {
+            let range = rng.range(2..=20) as usize;
+            if !(depth + delay).is_multiple_of(2 * (range - 1)) {
+                lines.push(format!("{}: {}", depth, range));
+                depth += rng.range(1..=3) as usize;
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+}
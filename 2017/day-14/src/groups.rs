@@ -1,81 +1,48 @@
-extern crate bit_vec;
-use bit_vec::BitVec;
-
-const WIDTH: usize = 128;
+use disjoint_set::DisjointSet;
+use grid::Grid;
 
 pub struct GroupBuilder {
-    pub groups: Vec<[Option<u16>; 128]>
+    /// Each used square's region, numbered from 1 in reading order.
+    pub groups: Grid<usize>,
+    pub count: usize
 }
 
 impl GroupBuilder {
-    pub fn new(bits: &Vec<BitVec>) -> GroupBuilder {
-        let groups = GroupBuilder::build(&bits);
-
-        // All rows are "grouped", but we need to consider column "neighbors"
-        let mut gb = GroupBuilder { groups: groups };
-
-        for row_i in 1..WIDTH {
-            for col_i in 0..WIDTH {
-                if let (&Some(cell), &Some(up_cell)) = (gb.get_cell(row_i, col_i), gb.get_cell(row_i - 1, col_i)) {
-                    if cell != up_cell {
-                        gb.substitute(cell, up_cell);
+    pub fn new(squares: &Grid<bool>) -> GroupBuilder {
+        let width = squares.width();
+        let index = |(x, y): (usize, usize)| y * width + x;
+        let mut regions = DisjointSet::new(width * squares.height());
+
+        // Join every used square to the used squares right of and below it.
+        for (pos, &used) in squares.cells() {
+            if !used { continue; }
+
+            for step in [(1, 0), (0, 1)] {
+                if let Some(neighbour) = squares.step(pos, step) {
+                    if squares.get(neighbour) == Some(&true) {
+                        regions.union(index(pos), index(neighbour));
                     }
                 }
             }
         }
 
-        gb
-    }
-
-    fn build(bits: &Vec<BitVec>) -> Vec<[Option<u16>; 128]> {
-        let mut groups = vec![];
-        let mut max_group_id = 0;
-
-        for bv in bits.iter() {
-            let mut group_row = [None; 128];
-            let mut current_row_group: Option<u16> = None;
+        // Number the regions as they're first seen.
+        let mut numbers = vec![0; regions.len()];
+        let mut groups = Grid::new(width, squares.height());
+        let mut count = 0;
 
-            for (col_i, b) in bv.iter().enumerate() {
-                current_row_group = if b {
-                    let group_id = match current_row_group {
-                        Some(cr) => cr,
-                        None     => {
-                            max_group_id += 1;
-                            max_group_id
-                        }
-                    };
+        for (pos, &used) in squares.cells() {
+            if !used { continue; }
 
-                    Some(group_id)
-                } else {
-                    None
-                };
-
-                group_row[col_i] = current_row_group;
+            let root = regions.find(index(pos));
+            if numbers[root] == 0 {
+                count += 1;
+                numbers[root] = count;
             }
 
-            groups.push(group_row);
-        }
-
-        groups
-    }
-
-    /// substitute r for all instances of s
-    fn substitute(&mut self, s: u16, r: u16) {
-        for row in self.groups.iter_mut() {
-            for og in row.iter_mut() {
-                if let &mut Some(g) = og {
-                    if g == s {
-                        *og = Some(r);
-                    }
-                }
-            }
+            groups.set(pos, numbers[root]);
         }
-    }
 
-    /// get cell at (x,y)
-    fn get_cell(&self, row_i: usize, col_i: usize) -> &Option<u16> {
-        let row = self.groups.get(row_i).unwrap();
-        row.get(col_i).unwrap()
+        GroupBuilder { groups, count }
     }
 }
-
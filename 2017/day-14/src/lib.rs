@@ -1,92 +1,81 @@
-pub fn knot_hash(s: &str) -> String {
-    let mut ascii: Vec<usize> = s.chars().map(|c| c as usize).collect();
-    let mut standard_suffix = vec![17, 31, 73, 47, 23];
-    ascii.append(&mut standard_suffix);
+// http://adventofcode.com/2017/day/14
 
-    let mut list = List::new_with_capacity(256);
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
 
-    // Twist 64 times
-    for _ in 0..64 {
-        list.twist_by_lengths(&ascii);
-    }
+mod groups;
+use groups::GroupBuilder;
 
-    list.dense_hash()
-}
+use std::fmt;
+
+const SIZE: usize = 128;
 
-struct List {
-    marks: Vec<u32>,
-    index: usize,
-    skip_size: usize
+pub struct Grid {
+    squares: grid::Grid<bool>
 }
 
-impl List {
-    pub fn new_with_capacity(c: usize) -> List {
-        let mut marks = Vec::with_capacity(c);
+impl Grid {
+    pub fn new(input: &str) -> Grid {
+        let mut squares = grid::Grid::filled(SIZE, SIZE, false);
 
-        for i in 0..c { 
-            marks.push(i as u32); 
+        for y in 0..SIZE {
+            let s = format!("{}-{}", input, y);
+            let digest = knot_hash::digest(&s);
+
+            // Most significant bit of the first byte on the left.
+            for x in 0..SIZE {
+                squares.set((x, y), digest[x / 8] & (0x80 >> (x % 8)) != 0);
+            }
         }
 
-        List {
-            marks: marks,
-            index: 0,
-            skip_size: 0
+        Grid {
+            squares
         }
     }
 
-    fn circular_index(&self, i: usize) -> usize {
-        i % self.marks.len()
+    pub fn used_squares(&self) -> usize {
+        self.squares.count(|&used| used)
     }
+}
 
-    pub fn len(&self) -> usize {
-        self.marks.len()
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.squares.render(|b| match b { Some(&true) => '#', _ => '.' }))
     }
+}
 
-    pub fn twist(&mut self, length: usize) {
-        if length > self.len() { panic!("Can't twist longer than list length!"); }
-
-        let sublist_begin = self.index; 
-        let sublist_end = self.index + length - 1;
-
-        // Reverse the order of that length of elements in the list, starting with the element at the current position.
-        for i in 0..(length / 2) {
-            let a_index = self.circular_index(sublist_begin + i);
-            let b_index = self.circular_index(sublist_end - i);
-
-            let old_a_value = { *self.marks.get(a_index).unwrap() };
-            let old_b_value = { *self.marks.get(b_index).unwrap() };
+impl fmt::Display for GroupBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: Vec<String> = (0..self.groups.height()).map(|y| group_row(self.groups.row(y))).collect();
+        write!(f, "{}", s.join("\n"))
+    }
+}
 
-            {
-                let mut a = self.marks.get_mut(a_index).unwrap();
-                *a = old_b_value;
-            }
+fn group_row<'a, I: Iterator<Item = Option<&'a usize>>>(row: I) -> String {
+    let v: Vec<String> = row.map(|n| match n {
+        Some(x) => format!("{:3}", x),
+        None    => "  .".to_string(),
+    }).collect();
 
-            {
-                let mut b = self.marks.get_mut(b_index).unwrap();
-                *b = old_a_value;
-            }
-        }
+    v.join(" ")
+}
 
-        // Move the current position forward by that length plus the skip size.
-        self.index += length + self.skip_size;
+impl Solution for Grid {
+    type Input = Grid;
 
-        // Increase the skip size by one.
-        self.skip_size += 1;
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Ok(Grid::new(input.trim()))
     }
 
-    pub fn twist_by_lengths(&mut self, lengths: &Vec<usize>) {
-        for i in lengths {
-            self.twist(*i);
-        }
+    fn part1(grid: &Grid) -> Answer {
+        grid.used_squares().into()
     }
 
-    pub fn dense_hash(&self) -> String {
-        let chunks_xor: Vec<u32> = self.marks.chunks(16)
-            .map(|chunk| { chunk.iter().fold(0, |acc, c| acc ^ c) })
-            .collect()
-        ;
+    fn part2(grid: &Grid) -> Answer {
+        GroupBuilder::new(&grid.squares).count.into()
+    }
 
-        let hexed_chunks: Vec<String> = chunks_xor.iter().map(|n| format!("{:02x}", n)).collect();
-        hexed_chunks.join("")
+    /// An eight letter key. `size` doesn't change anything; the grid is always 128x128.
+    fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        Some(rng.letters(8))
     }
 }
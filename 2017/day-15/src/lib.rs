@@ -0,0 +1,103 @@
+// http://adventofcode.com/2017/day/15
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Generator {
+    factor: u64,
+    divisor: u64,
+    value: u64
+}
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.value = (self.value * self.factor) % self.divisor;
+        self.value
+    }
+}
+
+const GEN_A_FACTOR: u64 =        16_807;
+const GEN_B_FACTOR: u64 =        48_271;
+const DIVISOR:      u64 = 2_147_483_647;
+const TWO_16:       u64 =        65_536;
+
+impl Solution for Generator {
+    /// Starting values for generators A and B.
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        let mut names = ["A", "B"].iter();
+        let starts = aoc_core::parse_lines(input, |line| {
+            let line = line.trim();
+            let name = names.next().ok_or_else(|| ParseError::within(line, line, "end of input"))?;
+            let prefix = format!("Generator {} starts with ", name);
+
+            let start = line.strip_prefix(&prefix)
+                .ok_or_else(|| ParseError::within(line, line, format!("{}<value>", prefix)))?;
+            // Anything bigger would overflow the first multiplication.
+            start.parse::<u64>().ok()
+                .filter(|&start| start < DIVISOR)
+                .ok_or_else(|| ParseError::within(line, start, format!("a starting value below {}", DIVISOR)))
+        })?;
+
+        match starts[..] {
+            [gen_a_init, gen_b_init] => Ok((gen_a_init, gen_b_init)),
+            []                       => Ok((666, 666)),
+            _                        => Err(ParseError::new(2, 1, "", "Generator B starts with <value>")),
+        }
+    }
+
+    fn part1(&(gen_a_init, gen_b_init): &(u64, u64)) -> Answer {
+        let mut gen_a = Generator { factor: GEN_A_FACTOR, divisor: DIVISOR, value: gen_a_init };
+        let mut gen_b = Generator { factor: GEN_B_FACTOR, divisor: DIVISOR, value: gen_b_init };
+
+        let matches = (0..40_000_000).fold(vec![], |mut acc, _| {
+            let (va, vb) = (gen_a.next(), gen_b.next());
+
+            // Compare lowest 16 bits
+            if va % TWO_16 == vb % TWO_16 {
+                acc.push((va, vb));
+            }
+
+            acc
+        });
+
+        matches.len().into()
+    }
+
+    fn part2(&(gen_a_init, gen_b_init): &(u64, u64)) -> Answer {
+        let mut gen_a = Generator { factor: GEN_A_FACTOR, divisor: DIVISOR, value: gen_a_init };
+        let mut gen_b = Generator { factor: GEN_B_FACTOR, divisor: DIVISOR, value: gen_b_init };
+
+        let matches = (0..5_000_000).fold(vec![], |mut acc, _| {
+            let mut va = gen_a.next();
+            while !va.is_multiple_of(4) {
+                va = gen_a.next();
+            }
+
+            let mut vb = gen_b.next();
+            while !vb.is_multiple_of(8) {
+                vb = gen_b.next();
+            }
+
+            // Compare lowest 16 bits
+            if va % TWO_16 == vb % TWO_16 {
+                acc.push((va, vb));
+            }
+
+            acc
+        });
+
+        matches.len().into()
+    }
+
+    /// Two starting values. `size` doesn't change anything; the generators always run for
+    /// the same number of rounds.
+    fn generate(rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        Some(format!(
+            "Generator A starts with {}\nGenerator B starts with {}",
+            rng.range(1..=2_147_483_646),
+            rng.range(1..=2_147_483_646)
+        ))
+    }
+}
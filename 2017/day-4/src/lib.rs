@@ -1,14 +1,13 @@
 // http://adventofcode.com/2017/day/4
 
-#![feature(entry_and_modify)]
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use std::io::{self, Read};
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
 
-fn count_valid_phrases<F>(phrases: &Vec<&str>, filter: &F) -> u32
+fn count_valid_phrases<F>(phrases: &[String], filter: &F) -> u32
 where F: Fn(&str) -> bool {
-    let valid_phrases:Vec<&&str> = phrases
+    let valid_phrases:Vec<&String> = phrases
         .iter()
         .filter(|p| filter(p))
         .collect()
@@ -82,17 +81,48 @@ fn part2_is_valid(phrase:&str) -> bool {
     true
 }
 
-fn main() {
-    let mut input = String::new();
-    let mut stdin = io::stdin();
-    if let Err(why) = stdin.read_to_string(&mut input) { panic!("Could not read STDIN: {}", why); }
+/// High-Entropy Passphrases
+pub struct Passphrase;
 
-    let lines:Vec<&str> = input.trim().split('\n').collect();
+impl Solution for Passphrase {
+    type Input = Vec<String>;
 
-    let part1_solution = count_valid_phrases(&lines, &part1_is_valid);
-    println!("Pt 1: {}", part1_solution);
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(aoc_core::lines(input).iter().map(|s| s.to_string()).collect())
+    }
 
-    let part2_solution = count_valid_phrases(&lines, &part2_is_valid);
-    println!("Pt 2: {}", part2_solution);
-}
+    fn part1(lines: &Vec<String>) -> Answer {
+        count_valid_phrases(lines, &part1_is_valid).into()
+    }
 
+    fn part2(lines: &Vec<String>) -> Answer {
+        count_valid_phrases(lines, &part2_is_valid).into()
+    }
+
+    /// `size` passphrases, some with a repeated word and some with an anagram of one.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let lines: Vec<String> = (0..size.unwrap_or(512).max(1)).map(|_| {
+            let mut words: Vec<String> = (0..rng.range(5..=10))
+                .map(|_| {
+                    let len = rng.range(2..=7) as usize;
+                    rng.letters(len)
+                })
+                .collect();
+
+            if rng.one_in(4) {
+                let word = rng.pick(&words).clone();
+                words.push(word);
+            }
+            if rng.one_in(4) {
+                let mut letters: Vec<char> = rng.pick(&words).chars().collect();
+                rng.shuffle(&mut letters);
+                words.push(letters.into_iter().collect());
+            }
+
+            rng.shuffle(&mut words);
+            words.join(" ")
+        }).collect();
+
+        Some(lines.join("\n"))
+    }
+}
@@ -0,0 +1,103 @@
+// http://adventofcode.com/2017/day/5
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::str::FromStr;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct InstructionList {
+    // Current instruction index. Offsets are read as i32 but kept as i64, so jumping by one and
+    // adjusting it can't overflow.
+    i: i64,
+
+    // Number of steps taken
+    steps: u64,
+
+    // Instruction list
+    instructions: Vec<i64>
+}
+
+impl InstructionList {
+    pub fn new(l:Vec<i32>) -> InstructionList {
+        InstructionList {
+            i: 0,       
+            steps: 0,
+            instructions: l.into_iter().map(i64::from).collect()
+        }
+    }
+
+    /*
+     * - Evaluate the jump instruction and update self.i to the new index
+     * - Increment the original instruction by 1
+     * - Return self.i
+     */
+    pub fn next<F>(&mut self, increment_instruction: F) -> Option<i64> 
+        where F: Fn(i64) -> i64 {
+        let initial_i = self.i;
+
+        let index = usize::try_from(self.i).ok()?;
+
+        if let Some(instruction) = self.instructions.get_mut(index) {
+            let offset = *instruction;
+            self.i = initial_i + offset;
+            self.steps += 1;
+            *instruction += increment_instruction(offset);
+            Some(self.i)
+        } else {
+            None
+        }
+    }
+} 
+
+impl fmt::Display for InstructionList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells:Vec<String> = self.instructions.iter()
+            .enumerate()
+            .map(|(i, s)| format!("{}{}{}", if (self.i as usize) == i { "(" } else { "" }, s, if (self.i as usize) == i { ")" } else { "" }))
+
+            .collect()
+        ;
+
+        write!(f, "{}", cells.join(" "))
+    }
+}
+
+fn solution<F>(offsets:&[i32], increment_instruction: F) -> u64 
+    where F: Fn(i64) -> i64 {
+    let mut instructions = InstructionList::new(offsets.to_vec());
+
+    while instructions.next(&increment_instruction).is_some() {}
+
+    instructions.steps
+}
+
+impl Solution for InstructionList {
+    /// Jump offsets, one per line.
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        aoc_core::parse_lines(input, |s| {
+            let s = s.trim();
+            i32::from_str(s).map_err(|_| ParseError::within(s, s, "a jump offset"))
+        })
+    }
+
+    fn part1(offsets: &Vec<i32>) -> Answer {
+        solution(offsets, |_x| 1).into()
+    }
+
+    fn part2(offsets: &Vec<i32>) -> Answer {
+        solution(offsets, |x| if x >= 3 { -1 } else { 1 }).into()
+    }
+
+    /// `size` jump offsets, mostly backwards like a real input. The steps taken grow about with
+    /// the square of `size`.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let offsets: Vec<String> = (0..size.unwrap_or(1000).max(1))
+            .map(|i| rng.range(-(i as i64)..=2).to_string())
+            .collect();
+
+        Some(offsets.join("\n"))
+    }
+}
@@ -0,0 +1,181 @@
+// http://adventofcode.com/2017/day/6
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::fmt;
+use std::collections::HashSet;
+
+struct MemoryBank {
+    // Banks start out with a u32's worth at most, but can gather more than that between them.
+    block_count: u64
+}
+
+impl MemoryBank {
+    fn len(&self) -> u64 {
+        self.block_count
+    }
+
+    fn clear_blocks(&mut self) -> u64 {
+        self.block_count = 0;
+        self.block_count
+    }
+
+    fn add_blocks(&mut self, blocks: u64) -> u64 {
+        self.block_count += blocks;
+        self.block_count
+    }
+}
+
+impl fmt::Display for MemoryBank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:3}]", self.block_count)
+    }
+}
+
+pub struct MemoryArea {
+    memory_banks: Vec<MemoryBank>
+}
+
+impl MemoryArea {
+    /// Constructor
+    pub fn new(banks:Vec<u32>) -> MemoryArea {
+        MemoryArea {
+            memory_banks: banks
+                .iter()
+                .map(|&i| MemoryBank { block_count: i as u64 })
+                .collect()
+        }
+    }
+
+    /// Returns number of MemoryBanks available
+    pub fn len(&self) -> usize { self.memory_banks.len() }
+
+    pub fn is_empty(&self) -> bool { self.memory_banks.is_empty() }
+
+    /// Returns the index of the memory bank with the most blocks 
+    /// (ties won by the lowest-numbered memory bank)
+    pub fn largest_bank_index(&self) -> usize {
+        let largest_bank = self.memory_banks
+            .iter()
+            .enumerate()
+            .fold(
+                (0, 0), 
+                |(gi, glen), (i, mb)| 
+                if mb.len() > glen {
+                    (i, mb.len())
+                } else {
+                    (gi, glen)
+                }
+            )
+        ;
+
+        largest_bank.0
+    }
+
+    /// Rebalance the entire area until we find a previous state
+    pub fn rebalance(&mut self) -> u32 {
+        let mut states = HashSet::new();
+        let mut num_rebalances = 0;
+
+        states.insert(self.hash_key());
+
+        loop {
+            self.rebalance_single();
+            num_rebalances += 1;
+
+            if states.contains(&self.hash_key()) {
+                break;
+            } else {
+                states.insert(self.hash_key());
+            }
+        }
+
+        num_rebalances
+    }
+
+    /// Rebalance the largest bank with round-robin allocation
+    /// Starting with the following bank
+    fn rebalance_single(&mut self) {
+        if self.is_empty() { return; }
+
+        let lbi = self.largest_bank_index();
+        let blocks_to_distribute = self.memory_banks[lbi].len();
+
+        // Clear 
+        self.memory_banks[lbi].clear_blocks();
+
+        // Redistribute one at a time, starting with the next bank: every bank gets a share, and
+        // the ones first in line get one of what's left over too.
+        let banks = self.len() as u64;
+        let (share, left_over) = (blocks_to_distribute / banks, blocks_to_distribute % banks);
+        for i in 0..self.len() {
+            let cbi = (lbi + 1 + i) % self.len();
+            self.memory_banks[cbi].add_blocks(share + u64::from((i as u64) < left_over));
+        }
+    }
+
+    /// Return a string suitable for hashing
+    fn hash_key(&self) -> String {
+         let memory_bank_strs: Vec<String> = self.memory_banks
+            .iter()
+            .map(|mb| mb.to_string())
+            .collect()
+        ;
+
+        memory_bank_strs.join(" ")
+    }
+}
+
+impl fmt::Display for MemoryArea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let memory_bank_strs: Vec<String> = self.memory_banks
+            .iter()
+            .map(|mb| mb.to_string())
+            .collect()
+        ;
+
+        write!(f, "{}", memory_bank_strs.join(" "))
+    }
+}
+
+impl Solution for MemoryArea {
+    /// Initial block count of each bank.
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        let lines = aoc_core::parse_lines(input, |line| {
+            line.split('\t')
+                .map(str::trim)
+                .map(|s| s.parse().map_err(|_| ParseError::within(line, s, "a number of blocks")))
+                .collect::<Result<Vec<u32>, ParseError>>()
+        })?;
+
+        let banks: Vec<u32> = lines.into_iter().flatten().collect();
+        if banks.is_empty() {
+            return Err(ParseError::new(1, 1, "", "a number of blocks"));
+        }
+
+        Ok(banks)
+    }
+
+    fn part1(banks: &Vec<u32>) -> Answer {
+        let mut memory_area = MemoryArea::new(banks.clone());
+        memory_area.rebalance().into()
+    }
+
+    fn part2(banks: &Vec<u32>) -> Answer {
+        // Find the loop first, then count how long it takes to come back around.
+        let mut memory_area = MemoryArea::new(banks.clone());
+        memory_area.rebalance();
+        memory_area.rebalance().into()
+    }
+
+    /// `size` memory banks. Much past the real 16 and the cycles can get very long.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let banks: Vec<String> = (0..size.unwrap_or(16).max(1))
+            .map(|_| rng.range(0..=15).to_string())
+            .collect();
+
+        Some(banks.join("\t"))
+    }
+}
@@ -0,0 +1,355 @@
+// http://adventofcode.com/2017/day/7
+
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+use aoc_parse::Scanner;
+
+/// Towers are built, weighed and dropped by recursion, so no taller than this.
+const MAX_HEIGHT: usize = 1000;
+
+/// Disc on which our Programs are balancing
+#[derive(Debug)]
+struct Disc {
+    programs: Vec<Program>
+}
+
+impl Disc {
+    pub fn unbalanced_program(&self) -> Option<&Program> {
+        let mut weights_and_programs = HashMap::new();
+
+        for p in &self.programs {
+            let total_weight = p.total_weight();
+            let list = weights_and_programs.entry(total_weight).or_insert(vec![]);
+            list.push(p);
+        }
+
+        match weights_and_programs.len() {
+            0 => None,
+            1 => None,
+            2 => {
+                for v in weights_and_programs.values() {
+                    if v.len() == 1 {
+                        return Some(v.first().unwrap())
+                    }
+                }
+
+                None
+            },
+            _ => None
+        }
+    }
+
+    pub fn to_string(&self) -> Vec<String> {
+        self.programs.iter()
+            .map(|p| format!("{}", p))
+            .collect()
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.programs.iter()
+            .fold(0, |acc, p| acc + p.total_weight())
+    }
+}
+
+impl fmt::Display for Disc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string().join(", "))
+    }
+}
+
+/// Used for intermediate step in algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramReference {
+    name: String,
+    weight: u32,
+    program_names: Vec<String>
+}
+
+impl ProgramReference {
+    /// Parse a string into a ProgramReference.
+    ///
+    /// exmple: pbga (66)
+    /// result: name=pbga weight=66
+    /// exmple: fwft (72) -> ktlj, cntj, xhth
+    /// result: name=fwft weight=72 child program names=ktlj,cntj,xhth
+    pub fn parse(s: &str) -> Result<ProgramReference, ParseError> {
+        Listing::parse(1, s).map(|listing| listing.reference())
+    }
+}
+
+/// A line of the input as it was written, to point at when it doesn't fit with the others.
+struct Listing<'a> {
+    number: usize,
+    line: &'a str,
+    name: &'a str,
+    weight: u32,
+    held: Vec<&'a str>
+}
+
+impl<'a> Listing<'a> {
+    fn parse(number: usize, line: &'a str) -> Result<Listing<'a>, ParseError> {
+        aoc_parse::parse(line.trim(), |s| {
+            let name = s.expecting("a program name", Scanner::identifier)?;
+            s.literal(" ")?;
+            let weight = s.bracketed("(", ")", |s| s.expecting("a weight", Scanner::unsigned))?;
+
+            let held: Vec<&str> = if s.optional(|s| s.literal(" -> ")).is_some() {
+                s.expecting("program names", |s| s.separated(", ", Scanner::identifier))?
+            } else {
+                vec![]
+            };
+
+            Ok(Listing { number, line, name, weight, held })
+        })
+    }
+
+    /// An error about `text`, which must be part of this line.
+    fn error(&self, text: &str, expected: &str) -> ParseError {
+        ParseError::within(self.line, text, expected).on_line(self.number)
+    }
+
+    fn reference(&self) -> ProgramReference {
+        ProgramReference {
+            name: self.name.to_string(),
+            weight: self.weight,
+            program_names: self.held.iter().map(|n| n.to_string()).collect()
+        }
+    }
+}
+
+impl fmt::Display for ProgramReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option_str = if !self.program_names.is_empty() {
+            format!(" -> {}", self.program_names.join(", ")).to_string()
+        } else {
+            "".to_string()
+        };
+
+        write!(f, "{} ({}){}", self.name, self.weight, option_str)
+    }
+}
+
+#[derive(Debug)]
+pub struct Program {
+    name: String,
+    weight: u32,
+    disc: Option<Disc>
+}
+
+impl Program {
+    /// Return personal weight + sum of child weights
+    /// Weights are u32, so the total is kept in a u64 that a tower can't outgrow.
+    pub fn total_weight(&self) -> u64 {
+        self.weight as u64 + if let Some(ref d) = self.disc {
+            d.total_weight()
+        } else {
+            0
+        }
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option_str = if let Some(ref disc) = self.disc {
+            format!(" -> {}", disc).to_string()
+        } else {
+            "".to_string()
+        };
+
+        write!(f, "{} ({}){}", self.name, self.weight, option_str)
+    }
+}
+
+fn build_program(
+    prfn: String,
+    prfs: &HashMap<String, &ProgramReference>
+) -> Program {
+    let prf = prfs.get(&prfn).expect("checked by parse");
+    let odisc = if prf.program_names.is_empty() {
+        None
+    } else {
+        let programs = prf.program_names
+            .iter()
+            .map(|pn| build_program(pn.to_string(), prfs))
+            .collect()
+        ;
+
+        Some(Disc { programs })
+    };
+
+    Program { 
+        name: prf.name.to_string(), 
+        weight: prf.weight, 
+        disc: odisc
+    }
+}
+
+fn find_unbalanced_program(p: &Program) -> (Option<&Program>, Option<&Disc>) {
+    if let Some(ref d) = p.disc {
+        if let Some(up) = d.unbalanced_program() {
+            if let (Some(ucp), Some(ucd)) = find_unbalanced_program(up) {
+                (Some(ucp), Some(ucd))
+            } else {
+                (Some(up), Some(d))
+            }
+        } else {
+            (None, None)
+        }
+    } else {
+        (None, None)
+    }
+}
+
+/// Return the weight needed to balance the tower
+fn find_part2_solution(root: &Program) -> i64 {
+    if let (Some(p), Some(d)) = find_unbalanced_program(root) {
+        let target_total_weight = d.programs.iter()
+            .find(|dp| dp.total_weight() != p.total_weight())
+            .map(|dp| dp.total_weight())
+            .unwrap()
+        ;
+
+        // Negative if even a weightless program would be too heavy.
+        let difference = target_total_weight as i64 - p.total_weight() as i64;
+        p.weight as i64 + difference
+    } else {
+        0
+    }
+}
+
+impl Solution for Program {
+    /// The bottom program, with the rest of the tower balanced on top.
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program, ParseError> {
+        let listings: Vec<Listing> = aoc_core::numbered_lines(input)
+            .map(|(number, line)| Listing::parse(number, line).map_err(|e| e.on_line(number)))
+            .collect::<Result<Vec<Listing>, ParseError>>()?;
+
+        // Make sure it's a single tower before building it: every program held up is listed, nobody
+        // is held up twice, and exactly one program holds up the rest.
+        let all_held: HashSet<&str> = listings.iter().flat_map(|l| l.held.iter().copied()).collect();
+        let mut named: HashSet<&str> = HashSet::new();
+        for listing in &listings {
+            if !named.insert(listing.name) {
+                return Err(listing.error(listing.name, "a program that isn't listed already"));
+            }
+        }
+
+        let mut held: HashSet<&str> = HashSet::new();
+        let mut root_name = None;
+        for listing in &listings {
+            if !all_held.contains(listing.name) && root_name.replace(listing.name).is_some() {
+                return Err(listing.error(listing.name, "only one program at the bottom, not held up by any other"));
+            }
+
+            for &held_name in &listing.held {
+                if !named.contains(held_name) {
+                    return Err(listing.error(held_name, "a program from the list"));
+                }
+                if !held.insert(held_name) {
+                    return Err(listing.error(held_name, "a program that nothing else is holding up"));
+                }
+            }
+        }
+
+        let Some(root_name) = root_name else {
+            // Everybody's holding up somebody else, so they go round in a loop: point at where the
+            // first program listed is held up, which is part of it.
+            let Some(first) = listings.first() else {
+                return Err(ParseError::new(1, 1, "", "a program"));
+            };
+            let holder = listings.iter().find(|l| l.held.contains(&first.name)).expect("everybody's held");
+            let name = holder.held.iter().find(|&&n| n == first.name).unwrap();
+            return Err(holder.error(name, "a tower, not programs holding each other up in a loop"));
+        };
+
+        // Climb the tower a level at a time, and point at the first program held up too high.
+        let by_name: HashMap<&str, &Listing> = listings.iter().map(|l| (l.name, l)).collect();
+        let mut level = vec![by_name[root_name]];
+        for _ in 1..MAX_HEIGHT {
+            level = level.iter().flat_map(|l| l.held.iter().map(|&n| by_name[n])).collect();
+        }
+        if let Some(listing) = level.iter().find(|l| !l.held.is_empty()) {
+            return Err(listing.error(listing.held[0], "a tower no more than 1000 programs tall"));
+        }
+
+        let references: Vec<ProgramReference> = listings.iter().map(Listing::reference).collect();
+        let mut prs: HashMap<String, &ProgramReference> = HashMap::new();
+        for pr in &references { prs.insert(pr.name.to_string(), pr); }
+
+        Ok(build_program(root_name.to_string(), &prs))
+    }
+
+    fn part1(root: &Program) -> Answer {
+        root.name.to_string().into()
+    }
+
+    fn part2(root: &Program) -> Answer {
+        find_part2_solution(root).into()
+    }
+
+    /// A tower of `size` programs (at least four) in which every disc holds three or more, and
+    /// exactly one program has the wrong weight.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        // Much past 100,000 programs the tower is too heavy for the u32 weights.
+        let count = size.unwrap_or(1200).clamp(4, 100_000);
+
+        let mut names: Vec<String> = vec![];
+        let mut taken: HashSet<String> = HashSet::new();
+        while names.len() < count {
+            let len = rng.range(4..=7) as usize;
+            let name = rng.letters(len);
+            if taken.insert(name.clone()) { names.push(name); }
+        }
+
+        // Hand out children a level at a time, so the tower stays about as short as a real one
+        // (every level multiplies the weight). Some programs are left holding nothing. Children
+        // always come after their parent, so working backwards sees every disc before the
+        // program under it.
+        let mut children: Vec<Vec<usize>> = vec![vec![]; count];
+        let (mut parent, mut next) = (0, 1);
+        while next < count {
+            if parent + 1 == next || !rng.one_in(3) {
+                let left = count - next;
+                // Never leave one or two over, as nobody could hold them on their own.
+                let disc = if left <= 5 { left } else { (rng.range(3..=5) as usize).min(left - 3) };
+
+                children[parent].extend(next..next + disc);
+                next += disc;
+            }
+            parent += 1;
+        }
+
+        // Balance every disc by topping up the lighter towers on it.
+        let mut weights: Vec<u32> = (0..count).map(|_| rng.range(10..=99) as u32).collect();
+        let mut totals = vec![0; count];
+        for program in (0..count).rev() {
+            let heaviest = children[program].iter().map(|&c| totals[c]).max().unwrap_or(0);
+            for &child in &children[program] {
+                weights[child] += heaviest - totals[child];
+                totals[child] = heaviest;
+            }
+            totals[program] = weights[program] + heaviest * children[program].len() as u32;
+        }
+
+        // Then knock one of them off balance.
+        let wrong = rng.range(1..=count as i64 - 1) as usize;
+        let change = rng.range(1..=9) as u32;
+        if weights[wrong] > change && rng.one_in(2) { weights[wrong] -= change; } else { weights[wrong] += change; }
+
+        let mut lines: Vec<String> = (0..count).map(|program| {
+            let reference = ProgramReference {
+                name: names[program].to_string(),
+                weight: weights[program],
+                program_names: children[program].iter().map(|&c| names[c].to_string()).collect()
+            };
+            reference.to_string()
+        }).collect();
+        rng.shuffle(&mut lines);
+
+        Some(lines.join("\n"))
+    }
+}
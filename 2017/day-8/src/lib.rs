@@ -0,0 +1,272 @@
+// http://adventofcode.com/2017/day/8
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::fmt;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Inc,
+    Dec
+}
+
+impl Operation {
+    pub fn parse(s: &str) -> Option<Operation> {
+        match s {
+            "inc"   => Some(Operation::Inc),
+            "dec"   => Some(Operation::Dec),
+            _       => None
+        }
+    }
+
+    pub fn execute(&self, lh: i64, rh: i32) -> i64 {
+        match *self {
+            Operation::Inc => lh + rh as i64,
+            Operation::Dec => lh - rh as i64
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, 
+            "{}", 
+            match *self { 
+                Operation::Inc => "inc", 
+                Operation::Dec => "dec"
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Gt,
+    GtEq,
+    Lt,
+    LtEq,
+    Neq
+}
+
+impl Comparison {
+    pub fn parse(s: &str) -> Option<Comparison> {
+        match s {
+            "=="    => Some(Comparison::Eq),
+            ">"     => Some(Comparison::Gt),
+            ">="    => Some(Comparison::GtEq),
+            "<"     => Some(Comparison::Lt),
+            "<="    => Some(Comparison::LtEq),
+            "!="    => Some(Comparison::Neq),
+            _       => None
+        }
+    }
+
+    pub fn execute(&self, lh: i64, rh: i32) -> bool {
+        let rh = rh as i64;
+
+        match *self {
+            Comparison::Eq      => lh == rh,
+            Comparison::Gt      => lh >  rh,
+            Comparison::GtEq    => lh >= rh,
+            Comparison::Lt      => lh <  rh,
+            Comparison::LtEq    => lh <= rh,
+            Comparison::Neq     => lh != rh
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Comparison::Eq      => "==",
+            Comparison::Gt      => ">",
+            Comparison::GtEq    => ">=",
+            Comparison::Lt      => "<",
+            Comparison::LtEq    => "<=",
+            Comparison::Neq     => "!="
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+pub struct Register {
+    #[allow(dead_code)]
+    name: String,
+    /// Amounts are i32, so it would take billions of instructions to go past an i64.
+    value: i64
+}
+
+impl Register {
+    pub fn new(name: &str) -> Register {
+        Register { name: name.to_string(), value: 0 }
+   }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    register_name: String,
+    operation: Operation,
+    operation_value: i32,
+
+    test_register_name: String,
+    comparison: Comparison,
+    comparison_value: i32
+}
+
+impl Instruction {
+    /// Parse "b inc 5 if a > 1"
+    pub fn parse(s: &str) -> Result<Instruction, ParseError> {
+        let s = s.trim();
+        let tokens: Vec<&str> = s.split(' ').collect();
+
+        if tokens.len() != 7 || tokens[3] != "if" {
+            return Err(ParseError::within(s, s, "<register> inc|dec <amount> if <register> <comparison> <amount>"));
+        }
+
+        let amount = |t: &str| t.parse::<i32>().map_err(|_| ParseError::within(s, t, "an amount"));
+
+        Ok(Instruction {
+            register_name: tokens[0].to_string(),
+            operation: Operation::parse(tokens[1])
+                .ok_or_else(|| ParseError::within(s, tokens[1], "inc or dec"))?,
+            operation_value: amount(tokens[2])?,
+
+            test_register_name: tokens[4].to_string(),
+            comparison: Comparison::parse(tokens[5])
+                .ok_or_else(|| ParseError::within(s, tokens[5], "one of == != > >= < <="))?,
+            comparison_value: amount(tokens[6])?
+        })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, 
+            "{} {} {} if {} {} {}",
+            self.register_name,
+            self.operation,
+            self.operation_value,
+            self.test_register_name,
+            self.comparison,
+            self.comparison_value,
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct RegisterList {
+    registers: HashMap<String, Register>
+}
+
+impl RegisterList {
+    pub fn new() -> RegisterList {
+        RegisterList {
+            registers: HashMap::new() 
+        }
+    }
+
+    pub fn get(&mut self, n: &str) -> &mut Register {
+        self.registers
+            .entry(n.to_string())
+            .or_insert(Register::new(n))
+    }
+
+    /// The register holding the largest value, if there are any registers yet.
+    pub fn max(&self) -> Option<&Register> {
+        self.registers.values().max_by(|r1, r2| r1.value.cmp(&r2.value))
+    }
+}
+
+fn solutions(instructions: &[Instruction]) -> (i64, i64) {
+    let mut registers = RegisterList::new();
+    let mut all_time_max_value = 0;
+
+    for i in instructions {
+        let comparison_passes = {
+            let test_register = registers.get(&i.test_register_name);
+            i.comparison.execute(test_register.value, i.comparison_value)
+        };
+
+        if comparison_passes {
+            let updated_value = {
+                let register = registers.get(&i.register_name);
+                i.operation.execute(register.value, i.operation_value)
+            };
+
+            let register = registers.get(&i.register_name);
+            register.value = updated_value;
+
+            all_time_max_value = all_time_max_value.max(register.value);
+        }
+    }
+
+    (
+        // Pt1 Current maximum value (registers start at 0)
+        registers.max().map_or(0, |r| r.value),
+
+        // Pt2 All-time maximum value
+        all_time_max_value
+    )
+}
+
+impl Solution for RegisterList {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let instructions = aoc_core::parse_lines(input, Instruction::parse)?;
+        if instructions.is_empty() {
+            return Err(ParseError::new(1, 1, "", "an instruction"));
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Answer {
+        let (part1_solution, _) = solutions(instructions);
+        part1_solution.into()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Answer {
+        let (_, part2_solution) = solutions(instructions);
+        part2_solution.into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(1000).max(1);
+
+        // About forty instructions to a register, like the real thing.
+        let mut registers: Vec<String> = vec![];
+        while registers.len() < (count / 40).max(2) {
+            let len = rng.range(2..=3) as usize;
+            let name = rng.letters(len);
+            if !registers.contains(&name) { registers.push(name); }
+        }
+
+        let lines: Vec<String> = (0..count).map(|_| {
+            let instruction = Instruction {
+                register_name: rng.pick(&registers).clone(),
+                operation: if rng.one_in(2) { Operation::Inc } else { Operation::Dec },
+                operation_value: rng.range(-1000..=1000) as i32,
+
+                test_register_name: rng.pick(&registers).clone(),
+                comparison: match rng.below(6) {
+                    0 => Comparison::Eq,
+                    1 => Comparison::Gt,
+                    2 => Comparison::GtEq,
+                    3 => Comparison::Lt,
+                    4 => Comparison::LtEq,
+                    _ => Comparison::Neq
+                },
+                comparison_value: rng.range(-1000..=1000) as i32
+            };
+            instruction.to_string()
+        }).collect();
+
+        Some(lines.join("\n"))
+    }
+}
@@ -0,0 +1,256 @@
+// http://adventofcode.com/2017/day/9
+
+use aoc_core::{random::Rng, Answer, ParseError, Solution};
+
+use std::str::Chars;
+use std::iter::Enumerate;
+
+/// Groups are parsed, scored and dropped by recursion, so no deeper than this.
+const MAX_DEPTH: usize = 1000;
+
+enum Tag {
+    Garbage,
+    Group
+}
+
+enum Token {
+    GarbageOpen,
+    GarbageClose,
+    GarbageCancelNext,
+
+    GroupOpen,
+    GroupClose,
+
+    #[allow(dead_code)]
+    Character(char)
+}
+
+impl Token {
+    pub fn parse(c: char) -> Token {
+        match c {
+            '<' => Token::GarbageOpen,
+            '>' => Token::GarbageClose,
+            '!' => Token::GarbageCancelNext,
+            '{' => Token::GroupOpen,
+            '}' => Token::GroupClose,
+            _   => Token::Character(c)
+        }
+    }
+}
+
+pub struct Group {
+    // Child groups of this Group
+    groups: Vec<Group>,
+
+    #[allow(dead_code)]
+    index_begin: usize,
+    #[allow(dead_code)]
+    index_end: usize,
+
+    // What type is this?
+    tag: Tag,
+
+    // If garbage, number of non-skipped garbage chars
+    garbage_chars: u32
+
+    // This tag / garbage_chars business is pretty sloppy, I realize...
+}
+
+impl Group {
+    pub fn parse(s: &str) -> Result<Group, ParseError> {
+        if !s.starts_with('{') {
+            let first = &s[..s.chars().next().map_or(0, char::len_utf8)];
+            return Err(ParseError::within(s, first, "a group, starting with {"));
+        }
+
+        if let Some(at) = Group::too_deep(s) {
+            return Err(ParseError::within(s, &s[at..at + 1], "groups nested no more than 1000 deep"));
+        }
+
+        let mut chars = s.chars().enumerate();
+        chars.next();
+        Group::parse_group(0, &mut chars)
+            .ok_or_else(|| ParseError::within(s, &s[s.len()..], "the rest of the stream, up to the closing }"))
+    }
+
+    /// Where the first group nested deeper than `MAX_DEPTH` opens, if one does.
+    fn too_deep(s: &str) -> Option<usize> {
+        let (mut depth, mut garbage, mut cancelled) = (0, false, false);
+
+        for (at, c) in s.char_indices() {
+            if cancelled {
+                cancelled = false;
+                continue;
+            }
+
+            match (garbage, c) {
+                (true, '!')  => { cancelled = true; },
+                (true, '>')  => { garbage = false; },
+                (false, '<') => { garbage = true; },
+                (false, '{') => {
+                    depth += 1;
+                    if depth > MAX_DEPTH { return Some(at); }
+                },
+                (false, '}') => { depth -= 1; },
+                _ => {}
+            }
+
+            // Anything after the outermost group is left alone.
+            if depth == 0 && !garbage { break; }
+        }
+
+        None
+    }
+
+    /// The group opened at `index_begin`, or `None` if the stream ends first.
+    fn parse_group(index_begin: usize, chars: &mut Enumerate<Chars>) -> Option<Group> {
+        let mut o_group:Option<Group> = None;
+        let mut groups:Vec<Group> = vec![];
+
+        while let Some((i, oc)) = chars.next() {
+            let ot = Token::parse(oc);
+
+            match ot {
+                Token::GarbageOpen  => { groups.push(Group::parse_garbage(i, chars)?); },
+                Token::GroupOpen    => { groups.push(Group::parse_group(i, chars)?);   },
+                Token::GroupClose   => {
+                    let g = Group {
+                        groups,
+                        index_begin,
+                        index_end: i,
+                        tag: Tag::Group,
+                        garbage_chars: 0
+                    };
+
+                    o_group = Some(g);
+                    break;
+                },
+
+                _ => {} 
+            }
+        }
+
+        o_group
+    }
+
+    /// The garbage opened at `index_begin`, or `None` if the stream ends first.
+    fn parse_garbage(index_begin: usize, chars: &mut Enumerate<Chars>) -> Option<Group> {
+        let mut o_group:Option<Group> = None;
+        let mut garbage_chars = 0;
+
+        while let Some((i, oc)) = chars.next() {
+            let ot = Token::parse(oc);
+
+            match ot {
+                Token::GarbageClose => {
+                    o_group = Some(Group::new_garbage(index_begin, i, garbage_chars));
+                    break;
+                },
+
+                Token::GarbageCancelNext => {
+                    chars.next();
+                },
+
+                _ => { garbage_chars += 1; }
+            }
+        }
+
+        o_group
+    }
+
+    fn new_garbage(index_begin: usize, index_end: usize, garbage_chars: u32) -> Group {
+        Group {
+            tag: Tag::Garbage,
+            index_begin,
+            index_end,
+            groups: vec![],
+            garbage_chars
+        }
+    }
+
+    /// Write a random group `depth` deep to `stream`, taking groups from `budget` until it runs
+    /// out. The outermost group keeps adding children until it does.
+    fn generate_group(rng: &mut Rng, stream: &mut String, depth: usize, budget: &mut usize) {
+        *budget -= 1;
+        stream.push('{');
+
+        let mut children = 0;
+        while *budget > 0 && depth < 20 && (depth == 1 || children < rng.below(4)) {
+            if children > 0 { stream.push(','); }
+            children += 1;
+
+            if rng.one_in(3) {
+                Group::generate_garbage(rng, stream);
+            } else {
+                Group::generate_group(rng, stream, depth + 1, budget);
+            }
+        }
+
+        stream.push('}');
+    }
+
+    /// Write some random garbage to `stream`, with cancelled characters in it.
+    fn generate_garbage(rng: &mut Rng, stream: &mut String) {
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz{}<'\",!";
+
+        stream.push('<');
+        for _ in 0..rng.below(16) {
+            let c = *rng.pick(CHARS) as char;
+            if c == '!' {
+                stream.push('!');
+                stream.push(*rng.pick(b"!>ae{}") as char);
+            } else {
+                stream.push(c);
+            }
+        }
+        stream.push('>');
+    }
+
+    // Instance
+    
+    fn total_score(&self, depth: u32) -> u32 {
+        let my_score = match self.tag {
+            Tag::Group  => depth,
+            _           => 0
+        };
+
+        self.groups.iter().fold(
+            my_score,
+            |acc, g| acc + g.total_score(depth + 1)
+        )
+    }
+
+    fn total_garbage_chars(&self) -> u32 {
+        match self.tag {
+            Tag::Group => self.groups.iter() 
+                            .fold(0, |acc, g: &Group| acc + g.total_garbage_chars()),
+            Tag::Garbage => self.garbage_chars
+        }
+
+    }
+}
+
+impl Solution for Group {
+    /// The outermost group of the stream.
+    type Input = Group;
+
+    fn parse(input: &str) -> Result<Group, ParseError> {
+        Group::parse(input.trim())
+    }
+
+    fn part1(group: &Group) -> Answer {
+        group.total_score(1).into()
+    }
+
+    fn part2(group: &Group) -> Answer {
+        group.total_garbage_chars().into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut budget = size.unwrap_or(2000).max(1);
+        let mut stream = String::new();
+        Group::generate_group(rng, &mut stream, 1, &mut budget);
+
+        Some(stream)
+    }
+}
@@ -9,6 +9,7 @@
 #   % ./advent.rb 2018/day-7
 #   % ./advent.rb 2016/1
 #   % ./advent -t 2017/2
+#   % ./advent --json 2017/14     (Rust only: one JSON record per answer)
 #
 
 load './lib/advent-of-code.rb'
@@ -16,11 +17,20 @@ include AdventOfCode
 
 options, args = ARGV.partition { |a| a.start_with?("-") }
 use_test_input = options.include?("-t")
+format_json = options.include?("--json")
 
 date = Date.parse(args[0])
 
 exit_with_error("Couldn't parse Date from \"#{args[0]}\"") unless date
 
+# Rust days find their own input and samples; see crates/aoc/src/inputs.rs.
+if File.directory?(date.project_path) && detect_language(date.project_path) == :rust
+  runner_args = ["run", "#{date.year}/#{date.day}"]
+  runner_args << "--sample" if use_test_input
+  runner_args += ["--format", "json"] if format_json
+  exec("cargo", "run", "--quiet", "--release", "-p", "aoc", "--", *runner_args)
+end
+
 input_paths = date.input_paths(test: use_test_input)
 relative_input_path = input_paths.find { |path| File.exist?(path) }
 
@@ -34,7 +44,6 @@ if File.directory?(project_path = date.project_path)
   when :go then "cat #{absolute_input_path} | go run main.go"
   when :javascript then "if [ ! -d node_modules ]; then npm i; fi; cat #{absolute_input_path} | node main.js"
   when :python then "cat #{absolute_input_path} | python3 main.py"
-  when :rust then "cat #{absolute_input_path} | cargo run --quiet"
   when :swift then "cat #{absolute_input_path} | swift run"
   when :typescript then "if [ ! -d node_modules ]; then npm i; fi; cat #{absolute_input_path} | npx ts-node main.ts"
   else exit_with_error("#{project_path}: Could not run project type \"#{language}\"")
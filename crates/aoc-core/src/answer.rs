@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle, plus optional human-friendly detail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    value: String,
+    detail: Option<String>,
+}
+
+impl Answer {
+    pub fn new<T: fmt::Display>(value: T) -> Answer {
+        Answer { value: value.to_string(), detail: None }
+    }
+
+    /// Attach a description, e.g. "749/1091 real rooms".
+    pub fn with_detail<T: fmt::Display>(mut self, detail: T) -> Answer {
+        self.detail = Some(detail.to_string());
+        self
+    }
+
+    pub fn value(&self) -> &str { &self.value }
+
+    pub fn detail(&self) -> Option<&str> { self.detail.as_deref() }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer { Answer::new(value) }
+            }
+        )*
+    };
+}
+
+answer_from!(char, i32, i64, u16, u32, u64, usize, String, &str);
+
+/// Print the answer to one part of a puzzle. Multi-line answers start on their own line.
+pub fn print_answer<T: Into<Answer>>(part: u8, answer: T) {
+    let answer = answer.into();
+
+    if answer.value.contains('\n') {
+        println!("Pt {}:\n{}", part, answer.value);
+    } else if let Some(ref detail) = answer.detail {
+        println!("Pt {}: {} ({})", part, answer.value, detail);
+    } else {
+        println!("Pt {}: {}", part, answer.value);
+    }
+}
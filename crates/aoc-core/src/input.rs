@@ -0,0 +1,42 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone)]
+pub enum Input {
+    /// Everything on STDIN.
+    Stdin,
+
+    /// A file on disk.
+    File(PathBuf),
+
+    /// Input compiled into the binary, e.g. with `include_str!`.
+    Embedded(&'static str),
+}
+
+impl Input {
+    /// Read the whole input.
+    pub fn read(&self) -> io::Result<String> {
+        match *self {
+            Input::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            }
+            Input::File(ref path) => fs::read_to_string(path),
+            Input::Embedded(s) => Ok(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Input::Stdin => write!(f, "STDIN"),
+            Input::File(ref path) => write!(f, "{}", path.display()),
+            Input::Embedded(_) => write!(f, "embedded input"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared plumbing for every Advent of Code day: loading the puzzle input, splitting it into
+//! pieces, reporting malformed input, printing the answers, and the [`Solution`] trait every day
+//! implements, somewhere for animations to go ([`viz`]), and random numbers for generating inputs
+//! ([`random`]).
+
+mod answer;
+mod input;
+mod parse_error;
+mod solution;
+
+pub mod random;
+pub mod viz;
+
+pub use answer::{print_answer, Answer};
+pub use input::Input;
+pub use parse_error::{numbered_lines, parse_lines, ParseError};
+pub use solution::{Day, Drawing, Parsed, Part, Solution};
+
+/// Lines of the input, ignoring leading/trailing whitespace around the whole input.
+pub fn lines(input: &str) -> Vec<&str> {
+    input.trim().lines().collect()
+}
+
+/// Comma-separated values, each one trimmed.
+pub fn csv(input: &str) -> Vec<&str> {
+    input.trim().split(',').map(str::trim).collect()
+}
+
+/// Tab-separated values, each one trimmed.
+pub fn tabs(input: &str) -> Vec<&str> {
+    input.trim().split('\t').map(str::trim).collect()
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::fmt;
+
+/// Puzzle input that didn't look the way a parser expected.
+///
+/// `line` and `column` are 1-based; `column` counts characters, not bytes. Parsers that only see
+/// one line build the error with [`ParseError::within`] and let [`parse_lines`] fill in the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The text that couldn't be parsed. Empty when the input stopped short.
+    pub text: String,
+    /// What the parser wanted instead, e.g. "a turn (L or R) and a number of blocks".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new<T, E>(line: usize, column: usize, text: T, expected: E) -> ParseError
+    where
+        T: Into<String>,
+        E: Into<String>,
+    {
+        ParseError { line, column, text: text.into(), expected: expected.into() }
+    }
+
+    /// An error for `text`, which must be a slice of `line`; the column is worked out from where
+    /// `text` sits in `line`. Anything else is reported at the start of the line.
+    pub fn within<E: Into<String>>(line: &str, text: &str, expected: E) -> ParseError {
+        let start = line.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+        let column = match line.get(..offset) {
+            Some(before) if offset + text.len() <= line.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+
+        ParseError::new(1, column, text, expected)
+    }
+
+    /// The same error, found while parsing `piece`, a slice of the longer line `line`.
+    pub fn in_context(self, line: &str, piece: &str) -> ParseError {
+        let piece_column = ParseError::within(line, piece, "").column;
+        ParseError { column: self.column + piece_column - 1, ..self }
+    }
+
+    /// The same error, on line `line` of a bigger input.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    /// Quote the offending line of `source` with a caret under the bad text:
+    ///
+    /// ```text
+    ///   |
+    /// 3 | rotate column x=4 bye 3
+    ///   |                   ^^^^^ expected "by <amount>"
+    /// ```
+    pub fn annotate(&self, source: &str) -> String {
+        let line = source.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+        let gutter = self.line.to_string();
+        let pad = " ".repeat(gutter.len());
+        let indent = " ".repeat(self.column.saturating_sub(1));
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "{} |\n{} | {}\n{} | {}{} expected {}",
+            pad, gutter, line, pad, indent, carets, self.expected
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found \"{}\"", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse every line of the input with `f`, stamping errors with the line they came from.
+///
+/// Blank lines at the start and end of the input are skipped, like [`lines`](crate::lines).
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    numbered_lines(input).map(|(number, line)| f(line).map_err(|e| e.on_line(number))).collect()
+}
+
+/// The lines [`parse_lines`] parses, each with its 1-based line number, for parsers that find
+/// more wrong once every line has been read.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .skip_while(|&(_, line)| line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line))
+}
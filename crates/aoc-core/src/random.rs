@@ -0,0 +1,59 @@
+//! A small seeded random number generator for [`Solution::generate`](crate::Solution::generate).
+//!
+//! It's [SplitMix64](https://prng.di.unimi.it/splitmix64.c): not for cryptography, but quick, and
+//! the same seed gives the same input on every machine and every version of Rust.
+
+use std::ops::RangeInclusive;
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0` up to but not including `n`, which mustn't be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "nothing below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+
+        let span = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((self.next_u64() as u128 * span) >> 64) as i128) as i64
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// `len` random lowercase letters.
+    pub fn letters(&mut self, len: usize) -> String {
+        (0..len).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
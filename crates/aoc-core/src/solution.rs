@@ -0,0 +1,148 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::parse_error::ParseError;
+use crate::random::Rng;
+
+/// A solved puzzle: turn the raw input into something useful, then answer both parts from it.
+pub trait Solution {
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// A random puzzle input that `parse` accepts and both parts can answer, for stress tests and
+    /// benchmarks. `size` scales it (what it counts is up to the day); `None` means about the
+    /// size of a real input. Days without a generator return `None`.
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+
+    /// A picture of the puzzle being worked through, for seeing where a wrong answer went wrong.
+    /// Days that can't draw themselves return `None`.
+    fn draw(_input: &Self::Input, _drawing: Drawing) -> Option<String> {
+        None
+    }
+}
+
+/// What [`Solution::draw`] draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Drawing {
+    /// ASCII art, to read in a terminal.
+    Text,
+    /// An SVG image.
+    Svg,
+}
+
+/// One half of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Input parsed by a [`Day`], ready to hand back to [`Day::solve_part`].
+pub struct Parsed(Box<dyn Any>);
+
+/// A [`Solution`] with its types erased, so days can sit side by side in a table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part1: fn(&Parsed) -> Answer,
+    part2: fn(&Parsed) -> Answer,
+    generate: fn(&mut Rng, Option<usize>) -> Option<String>,
+    draw: fn(&Parsed, Drawing) -> Option<String>,
+}
+
+impl Day {
+    pub fn new<S>(year: u16, day: u8) -> Day
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Day {
+            year,
+            day,
+            parse: |input| S::parse(input).map(|parsed| Parsed(Box::new(parsed))),
+            part1: |parsed| S::part1(parsed.downcast::<S>()),
+            part2: |parsed| S::part2(parsed.downcast::<S>()),
+            generate: S::generate,
+            draw: |parsed, drawing| S::draw(parsed.downcast::<S>(), drawing),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    pub fn solve_part(&self, parsed: &Parsed, part: Part) -> Answer {
+        match part {
+            Part::One => (self.part1)(parsed),
+            Part::Two => (self.part2)(parsed),
+        }
+    }
+
+    /// A random input from the day's generator, the same every time for the same `seed`. `None`
+    /// if the day hasn't got one.
+    pub fn generate(&self, seed: u64, size: Option<usize>) -> Option<String> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    /// A picture of the parsed input from the day's [`Solution::draw`]. `None` if it hasn't got
+    /// one.
+    pub fn draw(&self, parsed: &Parsed, drawing: Drawing) -> Option<String> {
+        (self.draw)(parsed, drawing)
+    }
+
+    /// Parse the input and answer the requested parts.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>, ParseError> {
+        let parsed = self.parse(input)?;
+        Ok(parts.iter().map(|&part| (part, self.solve_part(&parsed, part))).collect())
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+impl Parsed {
+    fn downcast<S>(&self) -> &S::Input
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        self.0.downcast_ref().expect("parsed input came from a different Day")
+    }
+}
@@ -0,0 +1,119 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::{Backend, Throttle};
+
+/// Records frames as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file,
+/// for `asciinema play` or sharing. Each frame clears the screen and draws itself from the top.
+///
+/// The header needs the terminal size, which isn't known until every frame has been seen, so the
+/// recording is kept in memory and the file rewritten whenever the animation finishes.
+pub struct Asciicast {
+    path: PathBuf,
+    throttle: Throttle,
+    events: Vec<(Duration, String)>,
+    width: usize,
+    height: usize,
+    timestamp: u64,
+    started: Instant,
+    /// Time spent in pauses that weren't actually waited for.
+    paused: Duration,
+}
+
+impl Asciicast {
+    /// Creates the file straight away, so a bad path is reported before the day runs.
+    pub fn create<P: AsRef<Path>>(path: P, throttle: Throttle) -> io::Result<Asciicast> {
+        File::create(&path)?;
+
+        Ok(Asciicast {
+            path: path.as_ref().to_path_buf(),
+            throttle,
+            events: vec![],
+            width: 1,
+            height: 1,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            started: Instant::now(),
+            paused: Duration::ZERO,
+        })
+    }
+
+    /// A frame drawn `at` this point in the recording, rather than now.
+    pub fn draw_at(&mut self, at: Duration, frame: &str) {
+        for kept in self.throttle.offer(at, frame) {
+            self.push(kept);
+        }
+    }
+
+    fn now(&self) -> Duration {
+        self.started.elapsed() + self.paused
+    }
+
+    fn push(&mut self, (at, frame): (Duration, String)) {
+        let lines: Vec<&str> = frame.trim_end_matches('\n').lines().collect();
+        self.width = self.width.max(lines.iter().map(|l| l.chars().count()).max().unwrap_or(0));
+        self.height = self.height.max(lines.len());
+
+        // Home and clear, then the frame; the terminal wants carriage returns too.
+        self.events.push((at, format!("\x1b[H\x1b[2J{}", lines.join("\r\n"))));
+    }
+
+    fn write(&self) -> io::Result<()> {
+        let mut cast = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {}}}\n",
+            self.width, self.height, self.timestamp
+        );
+
+        for (at, data) in &self.events {
+            cast.push_str(&format!("[{:.6}, \"o\", {}]\n", at.as_secs_f64(), json_string(data)));
+        }
+
+        fs::write(&self.path, cast)
+    }
+}
+
+impl Backend for Asciicast {
+    fn due(&self) -> bool {
+        self.throttle.due(self.now())
+    }
+
+    fn draw(&mut self, frame: &str) {
+        self.draw_at(self.now(), frame);
+    }
+
+    fn pause(&mut self, duration: Duration) {
+        self.paused += duration;
+    }
+
+    fn finish(&mut self) {
+        if let Some(kept) = self.throttle.flush() {
+            self.push(kept);
+        }
+
+        if let Err(e) = self.write() {
+            eprintln!("Couldn't write {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
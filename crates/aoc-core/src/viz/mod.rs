@@ -0,0 +1,99 @@
+//! Animations for the days that have them, drawn wherever the runner points them.
+//!
+//! A day draws whole frames of plain text with [`draw`], holds one on screen with [`pause`], and
+//! calls [`finish`] at the end. Where the frames go is up to whoever runs the day: [`set`] picks a
+//! [`Backend`], and until it's called everything goes to [`Silent`], so tests, `aoc verify` and
+//! piped runs never wait on an animation.
+
+mod asciicast;
+mod record;
+mod terminal;
+mod throttle;
+
+#[cfg(feature = "ncurses")]
+mod ncurses;
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub use self::asciicast::Asciicast;
+pub use self::record::Recorder;
+pub use self::terminal::Terminal;
+pub use self::throttle::Throttle;
+
+#[cfg(feature = "ncurses")]
+pub use self::ncurses::Ncurses;
+
+/// Somewhere to show frames.
+pub trait Backend: Send {
+    /// Replace whatever's showing with `frame`, lines separated by `\n`.
+    fn draw(&mut self, frame: &str);
+
+    /// Leave the current frame up for `duration`.
+    fn pause(&mut self, _duration: Duration) {}
+
+    /// The animation is over; put the terminal back, flush the file.
+    fn finish(&mut self) {}
+
+    /// Whether frames go anywhere. Days skip building frames nobody will see.
+    fn active(&self) -> bool {
+        true
+    }
+
+    /// Whether a frame drawn now would be shown rather than dropped.
+    fn due(&self) -> bool {
+        self.active()
+    }
+}
+
+/// Throws every frame away.
+pub struct Silent;
+
+impl Backend for Silent {
+    fn draw(&mut self, _frame: &str) {}
+
+    fn active(&self) -> bool {
+        false
+    }
+}
+
+static BACKEND: Mutex<Option<Box<dyn Backend>>> = Mutex::new(None);
+
+/// Send every frame from now on to `backend`.
+pub fn set<B: Backend + 'static>(backend: B) {
+    *lock() = Some(Box::new(backend));
+}
+
+/// Whether frames are going anywhere.
+pub fn active() -> bool {
+    lock().as_ref().is_some_and(|b| b.active())
+}
+
+/// Whether a frame drawn now would be shown. Days that change faster than anyone could watch
+/// check this before building each frame, and draw their last one regardless.
+pub fn due() -> bool {
+    lock().as_ref().is_some_and(|b| b.due())
+}
+
+pub fn draw(frame: &str) {
+    if let Some(backend) = lock().as_mut() {
+        backend.draw(frame);
+    }
+}
+
+pub fn pause(duration: Duration) {
+    if let Some(backend) = lock().as_mut() {
+        backend.pause(duration);
+    }
+}
+
+pub fn finish() {
+    if let Some(backend) = lock().as_mut() {
+        backend.finish();
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Box<dyn Backend>>> {
+    // A day that panicked mid-frame hasn't left the backend in any state worth refusing to use.
+    BACKEND.lock().unwrap_or_else(|e| e.into_inner())
+}
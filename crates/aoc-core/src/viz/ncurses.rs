@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use ncurses::{addstr, endwin, initscr, mv, refresh};
+
+use super::Backend;
+
+/// Draws frames with ncurses, the way the 2016 days first did.
+#[derive(Default)]
+pub struct Ncurses {
+    started: bool,
+}
+
+impl Ncurses {
+    pub fn new() -> Ncurses {
+        Ncurses::default()
+    }
+}
+
+impl Backend for Ncurses {
+    fn draw(&mut self, frame: &str) {
+        if !self.started {
+            initscr();
+            self.started = true;
+        }
+
+        for (i, line) in frame.lines().enumerate() {
+            mv(i as i32, 0);
+            addstr(line);
+        }
+        refresh();
+    }
+
+    fn pause(&mut self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+
+    fn finish(&mut self) {
+        if self.started {
+            endwin();
+            self.started = false;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::{Backend, Throttle};
+
+/// Writes frames to a file, each under a header with its number and when it was drawn:
+///
+/// ```text
+/// --- frame 1 at 0.000s
+/// +----+
+/// |#...|
+/// +----+
+/// ```
+///
+/// Pauses count towards the time but aren't slept through, so recording is as quick as the day.
+pub struct Recorder {
+    out: BufWriter<File>,
+    throttle: Throttle,
+    frames: usize,
+    started: Instant,
+    /// Time spent in pauses that weren't actually waited for.
+    paused: Duration,
+}
+
+impl Recorder {
+    pub fn create<P: AsRef<Path>>(path: P, throttle: Throttle) -> io::Result<Recorder> {
+        Ok(Recorder {
+            out: BufWriter::new(File::create(path)?),
+            throttle,
+            frames: 0,
+            started: Instant::now(),
+            paused: Duration::ZERO,
+        })
+    }
+
+    fn now(&self) -> Duration {
+        self.started.elapsed() + self.paused
+    }
+
+    fn write(&mut self, (at, frame): (Duration, String)) {
+        self.frames += 1;
+        let _ = writeln!(self.out, "--- frame {} at {:.3}s", self.frames, at.as_secs_f64());
+        let _ = writeln!(self.out, "{}", frame.trim_end_matches('\n'));
+    }
+}
+
+impl Backend for Recorder {
+    fn due(&self) -> bool {
+        self.throttle.due(self.now())
+    }
+
+    fn draw(&mut self, frame: &str) {
+        for kept in self.throttle.offer(self.now(), frame) {
+            self.write(kept);
+        }
+    }
+
+    fn pause(&mut self, duration: Duration) {
+        self.paused += duration;
+    }
+
+    fn finish(&mut self) {
+        if let Some(kept) = self.throttle.flush() {
+            self.write(kept);
+        }
+        let _ = self.out.flush();
+    }
+}
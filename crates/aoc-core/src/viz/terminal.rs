@@ -0,0 +1,95 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use super::Backend;
+
+/// At most this many frames a second reach the terminal; the rest are dropped.
+const FPS: u32 = 60;
+
+/// Draws frames on stderr with ANSI escapes, each over the last, leaving stdout to the answers.
+/// Nothing above the animation is cleared, and the last frame stays on screen when it's done.
+pub struct Terminal {
+    out: io::Stderr,
+    /// A frame that came too soon after the last one to draw straight away.
+    pending: Option<String>,
+    last_drawn: Option<Instant>,
+    /// How tall the frame on screen is, to move back up over it.
+    height: usize,
+    started: bool,
+}
+
+impl Terminal {
+    pub fn new() -> Terminal {
+        Terminal { out: io::stderr(), pending: None, last_drawn: None, height: 0, started: false }
+    }
+
+    fn show(&mut self, frame: &str) {
+        let mut out = self.out.lock();
+
+        if !self.started {
+            // Hide the cursor.
+            let _ = write!(out, "\x1b[?25l");
+            self.started = true;
+        }
+
+        // Back up to the top of the last frame, then each line with whatever was after it cleared,
+        // then everything below in case this frame is shorter.
+        if self.height > 0 {
+            let _ = write!(out, "\x1b[{}A\r", self.height);
+        }
+        for line in frame.lines() {
+            let _ = writeln!(out, "{}\x1b[K", line);
+        }
+        let _ = write!(out, "\x1b[J");
+        let _ = out.flush();
+
+        self.height = frame.lines().count();
+        self.last_drawn = Some(Instant::now());
+    }
+
+    fn flush_pending(&mut self) {
+        if let Some(frame) = self.pending.take() {
+            self.show(&frame);
+        }
+    }
+}
+
+impl Default for Terminal {
+    fn default() -> Terminal {
+        Terminal::new()
+    }
+}
+
+impl Backend for Terminal {
+    fn due(&self) -> bool {
+        self.last_drawn.is_none_or(|t| t.elapsed() >= Duration::from_secs(1) / FPS)
+    }
+
+    fn draw(&mut self, frame: &str) {
+        if self.due() {
+            self.pending = None;
+            self.show(frame);
+        } else {
+            self.pending = Some(frame.to_string());
+        }
+    }
+
+    fn pause(&mut self, duration: Duration) {
+        self.flush_pending();
+        std::thread::sleep(duration);
+    }
+
+    fn finish(&mut self) {
+        self.flush_pending();
+
+        if self.started {
+            // Show the cursor again, below the last frame.
+            let _ = write!(self.out, "\x1b[?25h");
+            let _ = self.out.flush();
+            self.started = false;
+        }
+
+        self.last_drawn = None;
+        self.height = 0;
+    }
+}
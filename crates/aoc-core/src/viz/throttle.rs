@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+/// Decides which frames a recording keeps: no two closer together than a frame rate allows, and
+/// no more than a maximum in all. A frame that's dropped is held back, and kept after all if it
+/// stays on screen long enough to be seen (say, over a pause) or turns out to be the last one, so
+/// a recording always shows the pictures that were actually visible.
+pub struct Throttle {
+    gap: Duration,
+    max_frames: usize,
+    kept: usize,
+    last_kept: Option<Duration>,
+    held: Option<(Duration, String)>,
+}
+
+impl Throttle {
+    /// At most `fps` frames a second (0 for no limit) and `max_frames` in all.
+    pub fn new(fps: u32, max_frames: Option<usize>) -> Throttle {
+        let gap = if fps == 0 { Duration::ZERO } else { Duration::from_secs(1) / fps };
+        Throttle { gap, max_frames: max_frames.unwrap_or(usize::MAX), kept: 0, last_kept: None, held: None }
+    }
+
+    /// Whether a frame offered `at` this point in the recording would be kept.
+    pub fn due(&self, at: Duration) -> bool {
+        // Leave room for the final frame.
+        self.room(1) && self.last_kept.is_none_or(|last| at >= last + self.gap)
+    }
+
+    /// A frame drawn `at` this point. Returns the frames to record now, in order: perhaps the one
+    /// held back before it, then perhaps this one.
+    pub fn offer(&mut self, at: Duration, frame: &str) -> Vec<(Duration, String)> {
+        let mut kept = vec![];
+
+        if let Some((held_at, _)) = self.held {
+            if at >= held_at + self.gap && self.room(1) {
+                kept.extend(self.flush());
+            }
+        }
+
+        if self.due(at) {
+            self.held = None;
+            kept.push(self.keep(at, frame.to_string()));
+        } else {
+            self.held = Some((at, frame.to_string()));
+        }
+
+        kept
+    }
+
+    /// At the end: the frame held back, if any. It may take the last slot.
+    pub fn flush(&mut self) -> Option<(Duration, String)> {
+        if !self.room(0) {
+            return None;
+        }
+
+        let (at, frame) = self.held.take()?;
+        Some(self.keep(at, frame))
+    }
+
+    fn room(&self, reserved: usize) -> bool {
+        self.kept + reserved < self.max_frames
+    }
+
+    fn keep(&mut self, at: Duration, frame: String) -> (Duration, String) {
+        self.kept += 1;
+        self.last_kept = Some(at);
+        (at, frame)
+    }
+}
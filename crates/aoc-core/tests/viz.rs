@@ -0,0 +1,122 @@
+//! Recording animations: which frames the throttle keeps, and the asciicast file they end up in,
+//! fed frames at known points in the recording.
+
+use std::fs;
+use std::time::Duration;
+
+use aoc_core::viz::{Asciicast, Backend, Throttle};
+use tempfile::NamedTempFile;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// Offer a frame drawn at each of `times`, named for when it's drawn, then flush.
+fn run(throttle: &mut Throttle, times: &[u64]) -> Vec<(Duration, String)> {
+    let mut kept = vec![];
+    for &t in times {
+        kept.extend(throttle.offer(ms(t), &format!("frame {}", t)));
+    }
+    kept.extend(throttle.flush());
+    kept
+}
+
+/// The frames drawn at `times`, as [`run`] names them.
+fn frames(times: &[u64]) -> Vec<(Duration, String)> {
+    times.iter().map(|&t| (ms(t), format!("frame {}", t))).collect()
+}
+
+#[test]
+fn no_limits_keeps_everything() {
+    let mut throttle = Throttle::new(0, None);
+    assert_eq!(run(&mut throttle, &[0, 1, 1, 2, 500]), frames(&[0, 1, 1, 2, 500]));
+}
+
+#[test]
+fn no_faster_than_the_frame_rate() {
+    let mut throttle = Throttle::new(10, None);
+
+    assert!(throttle.due(ms(0)));
+    assert_eq!(throttle.offer(ms(0), "frame 0"), frames(&[0]));
+    assert!(!throttle.due(ms(99)));
+    assert!(throttle.due(ms(100)));
+
+    // 30 and 60 come too soon and are replaced, and 100 is a whole gap after 0. 150 is too soon
+    // as well, but stays up for a whole gap before 250 replaces it; 260 is the last.
+    assert_eq!(run(&mut throttle, &[30, 60, 100, 150, 250, 260]), frames(&[100, 150, 250, 260]));
+    assert_eq!(run(&mut throttle, &[300, 320, 340]), frames(&[340]));
+}
+
+#[test]
+fn keeps_a_dropped_frame_that_stays_up() {
+    let mut throttle = Throttle::new(10, None);
+
+    // 10 was too soon after 0, but was on screen for most of a second before 500 replaced it.
+    assert_eq!(throttle.offer(ms(0), "frame 0"), frames(&[0]));
+    assert_eq!(throttle.offer(ms(10), "frame 10"), vec![]);
+    assert_eq!(throttle.offer(ms(500), "frame 500"), frames(&[10, 500]));
+    assert_eq!(throttle.flush(), None);
+}
+
+#[test]
+fn max_frames_always_keeps_the_last() {
+    let mut throttle = Throttle::new(10, Some(3));
+    assert_eq!(run(&mut throttle, &[0, 100, 200, 300, 400]), frames(&[0, 100, 400]));
+
+    let mut throttle = Throttle::new(0, Some(1));
+    assert_eq!(run(&mut throttle, &[0, 100, 200]), frames(&[200]));
+
+    // With only one frame, it's the last.
+    let mut throttle = Throttle::new(0, Some(2));
+    assert_eq!(run(&mut throttle, &[0]), frames(&[0]));
+}
+
+/// Record `frames` at the given times to a cast file, and read it back.
+fn record(throttle: Throttle, frames: &[(u64, &str)]) -> String {
+    let file = NamedTempFile::new().unwrap();
+    let mut cast = Asciicast::create(file.path(), throttle).unwrap();
+
+    for &(t, frame) in frames {
+        cast.draw_at(ms(t), frame);
+    }
+    cast.finish();
+
+    fs::read_to_string(file.path()).unwrap()
+}
+
+#[test]
+fn asciicast_header_and_events() {
+    let text = record(Throttle::new(0, None), &[(0, "#.\n.#\n"), (1_500, "###\n\n#")]);
+    let lines: Vec<&str> = text.lines().collect();
+
+    // As wide and tall as the biggest frame.
+    let timestamp = lines[0].strip_prefix(r#"{"version": 2, "width": 3, "height": 3, "timestamp": "#).unwrap();
+    assert!(timestamp.strip_suffix('}').unwrap().parse::<u64>().is_ok(), "{}", lines[0]);
+
+    assert_eq!(&lines[1..], [r#"[0.000000, "o", "\u001b[H\u001b[2J#.\r\n.#"]"#, r#"[1.500000, "o", "\u001b[H\u001b[2J###\r\n\r\n#"]"#]);
+}
+
+#[test]
+fn asciicast_escapes_frames() {
+    let text = record(Throttle::new(0, None), &[(5, "say \"hi\" \\ \t\u{7f}\u{1}é")]);
+
+    // Only control characters below space need escapes; DEL and the rest go through as they are.
+    let event = "[0.005000, \"o\", \"\\u001b[H\\u001b[2Jsay \\\"hi\\\" \\\\ \\t\u{7f}\\u0001é\"]";
+    assert_eq!(text.lines().nth(1), Some(event));
+}
+
+#[test]
+fn asciicast_keeps_the_last_frame() {
+    let frames: Vec<(u64, String)> = (0..10).map(|i| (i * 10, format!("{}", i))).collect();
+    let frames: Vec<(u64, &str)> = frames.iter().map(|(t, f)| (*t, f.as_str())).collect();
+    let text = record(Throttle::new(20, Some(3)), &frames);
+
+    assert_eq!(
+        text.lines().skip(1).collect::<Vec<&str>>(),
+        [
+            r#"[0.000000, "o", "\u001b[H\u001b[2J0"]"#,
+            r#"[0.050000, "o", "\u001b[H\u001b[2J5"]"#,
+            r#"[0.090000, "o", "\u001b[H\u001b[2J9"]"#,
+        ]
+    );
+}
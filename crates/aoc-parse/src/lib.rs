@@ -0,0 +1,226 @@
+//! Small parsers for puzzle input, so days don't slice strings at fixed offsets.
+//!
+//! A [`Scanner`] walks one line. Its methods each read one thing (a literal, a number, an
+//! identifier) and move past it, and the combinators ([`Scanner::separated`],
+//! [`Scanner::bracketed`], [`Scanner::optional`]) take closures that read the rest. Anything that
+//! doesn't match is an [`aoc_core::ParseError`] pointing at the offending text:
+//!
+//! ```
+//! use aoc_parse::Scanner;
+//!
+//! // "rotate row y=0 by 4"
+//! let (row, by) = aoc_parse::parse("rotate row y=0 by 4", |s| {
+//!     s.literal("rotate row y=")?;
+//!     let row: u32 = s.unsigned()?;
+//!     s.literal(" by ")?;
+//!     Ok((row, s.unsigned::<u32>()?))
+//! })
+//! .unwrap();
+//! assert_eq!((row, by), (0, 4));
+//!
+//! let e = aoc_parse::parse("rotate row y=0 bye 4", |s| {
+//!     s.literal("rotate row y=")?;
+//!     s.unsigned::<u32>()?;
+//!     s.literal(" by ")
+//! })
+//! .unwrap_err();
+//! assert_eq!(e.to_string(), "line 1, column 15: expected \" by \", found \" bye\"");
+//! ```
+
+use std::str::FromStr;
+
+use aoc_core::ParseError;
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Read all of `line` with `f`, which must leave nothing behind.
+pub fn parse<'a, T, F>(line: &'a str, f: F) -> Result<T>
+where
+    F: FnOnce(&mut Scanner<'a>) -> Result<T>,
+{
+    let mut s = Scanner::new(line);
+    let parsed = f(&mut s)?;
+    s.end()?;
+    Ok(parsed)
+}
+
+/// A position in a line of input.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    line: &'a str,
+    /// Byte offset of the next character.
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Scanner<'a> {
+        Scanner { line, pos: 0 }
+    }
+
+    /// Everything not read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.pos == self.line.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Fails unless the whole line has been read.
+    pub fn end(&self) -> Result<()> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(ParseError::within(self.line, self.rest(), "the end of the line"))
+        }
+    }
+
+    /// An error at the current position, quoting the word found there.
+    pub fn error<E: Into<String>>(&self, expected: E) -> ParseError {
+        ParseError::within(self.line, self.word(), expected)
+    }
+
+    /// Exactly `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<&'a str> {
+        if self.rest().starts_with(literal) {
+            Ok(self.advance(literal.len()))
+        } else {
+            let found = self.prefix(literal.chars().count());
+            Err(ParseError::within(self.line, found, format!("\"{}\"", literal)))
+        }
+    }
+
+    /// The next `n` characters, whatever they are.
+    pub fn take(&mut self, n: usize) -> Result<&'a str> {
+        let taken = self.prefix(n);
+        if taken.chars().count() < n {
+            return Err(ParseError::within(self.line, taken, format!("{} characters", n)));
+        }
+
+        Ok(self.advance(taken.len()))
+    }
+
+    /// As many characters as match `pred`, possibly none.
+    pub fn take_while<P: Fn(char) -> bool>(&mut self, pred: P) -> &'a str {
+        let len = self.rest().find(|c| !pred(c)).unwrap_or(self.rest().len());
+        self.advance(len)
+    }
+
+    /// One or more digits.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T> {
+        self.number(false)
+    }
+
+    /// One or more digits, perhaps after a `-` or `+`.
+    pub fn signed<T: FromStr>(&mut self) -> Result<T> {
+        self.number(true)
+    }
+
+    /// A letter or `_`, then any letters, digits and `_`s.
+    pub fn identifier(&mut self) -> Result<&'a str> {
+        match self.peek() {
+            Some(c) if c.is_alphabetic() || c == '_' => Ok(self.take_while(|c| c.is_alphanumeric() || c == '_')),
+            _ => Err(self.error("a name")),
+        }
+    }
+
+    /// One or more `item`s with `separator` between them. A separator that isn't followed by
+    /// another item is left unread, so `a-b-7` as names separated by `-` reads `a` and `b`.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Scanner<'a>) -> Result<T>,
+    {
+        let mut items = vec![item(self)?];
+
+        while let Some(next) = self.optional(|s| {
+            s.literal(separator)?;
+            item(s)
+        }) {
+            items.push(next);
+        }
+
+        Ok(items)
+    }
+
+    /// `inner` between `open` and `close`, like the `(66)` in `pbga (66)`.
+    pub fn bracketed<T, F>(&mut self, open: &str, close: &str, inner: F) -> Result<T>
+    where
+        F: FnOnce(&mut Scanner<'a>) -> Result<T>,
+    {
+        self.literal(open)?;
+        let inside = inner(self)?;
+        self.literal(close)?;
+        Ok(inside)
+    }
+
+    /// Whatever `f` reads, or `None` (having read nothing) if it fails.
+    pub fn optional<T, F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Scanner<'a>) -> Result<T>,
+    {
+        let start = self.pos;
+        match f(self) {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                self.pos = start;
+                None
+            }
+        }
+    }
+
+    /// `f`, with any error saying it expected `expected` instead, e.g. "a sector ID" rather than
+    /// "a number".
+    pub fn expecting<T, F, E>(&mut self, expected: E, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Scanner<'a>) -> Result<T>,
+        E: Into<String>,
+    {
+        f(self).map_err(|e| ParseError { expected: expected.into(), ..e })
+    }
+
+    fn number<T: FromStr>(&mut self, signed: bool) -> Result<T> {
+        let start = self.pos;
+        if signed {
+            self.optional(|s| s.literal("-").or_else(|_| s.literal("+")));
+        }
+
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let number = &self.line[start..self.pos];
+        if digits.is_empty() {
+            self.pos = start;
+            return Err(self.error("a number"));
+        }
+
+        number.parse().map_err(|_| {
+            self.pos = start;
+            ParseError::within(self.line, number, "a smaller number")
+        })
+    }
+
+    /// The next word: a run of letters and digits, or else one character, or nothing at the end.
+    fn word(&self) -> &'a str {
+        let rest = self.rest();
+        match rest.find(|c: char| !c.is_alphanumeric()) {
+            Some(0) => self.prefix(1),
+            Some(len) => &rest[..len],
+            None => rest,
+        }
+    }
+
+    /// Up to `n` characters from here.
+    fn prefix(&self, n: usize) -> &'a str {
+        let rest = self.rest();
+        let len = rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+        &rest[..len]
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let read = &self.line[self.pos..self.pos + len];
+        self.pos += len;
+        read
+    }
+}
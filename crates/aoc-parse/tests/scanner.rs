@@ -0,0 +1,80 @@
+use aoc_parse::Scanner;
+
+#[test]
+fn literals_and_numbers() {
+    let parsed = aoc_parse::parse("rect 3x-2", |s| {
+        s.literal("rect ")?;
+        let x: u32 = s.unsigned()?;
+        s.literal("x")?;
+        Ok((x, s.signed::<i32>()?))
+    });
+
+    assert_eq!(parsed, Ok((3, -2)));
+}
+
+#[test]
+fn identifiers_separated() {
+    let parsed = aoc_parse::parse("fwft (72) -> ktlj, cntj, xhth", |s| {
+        let name = s.identifier()?;
+        s.literal(" ")?;
+        let weight: u32 = s.bracketed("(", ")", Scanner::unsigned)?;
+        s.literal(" -> ")?;
+        Ok((name, weight, s.separated(", ", Scanner::identifier)?))
+    });
+
+    assert_eq!(parsed, Ok(("fwft", 72, vec!["ktlj", "cntj", "xhth"])));
+}
+
+#[test]
+fn separator_without_an_item_is_left_unread() {
+    let mut s = Scanner::new("aaaaa-bbb-z-123[abxyz]");
+    assert_eq!(s.separated("-", Scanner::identifier), Ok(vec!["aaaaa", "bbb", "z"]));
+    assert_eq!(s.rest(), "-123[abxyz]");
+}
+
+#[test]
+fn optional_backtracks() {
+    let mut s = Scanner::new("pbga (66)");
+    assert_eq!(s.optional(|s| s.literal("pbgb")), None);
+    assert_eq!(s.optional(|s| s.literal("pbga")), Some("pbga"));
+    assert_eq!(s.rest(), " (66)");
+}
+
+#[test]
+fn take() {
+    let mut s = Scanner::new("(3x3)XYZ");
+    let length: usize = s
+        .bracketed("(", ")", |s| {
+            let length = s.unsigned()?;
+            s.literal("x")?;
+            s.unsigned::<u32>()?;
+            Ok(length)
+        })
+        .unwrap();
+    assert_eq!(s.take(length), Ok("XYZ"));
+    assert!(s.at_end());
+
+    let e = Scanner::new("XY").take(3).unwrap_err();
+    assert_eq!(e.to_string(), "line 1, column 1: expected 3 characters, found \"XY\"");
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    let e = aoc_parse::parse("rect 3xb", |s| {
+        s.literal("rect ")?;
+        s.unsigned::<u32>()?;
+        s.literal("x")?;
+        s.expecting("a height", Scanner::unsigned::<u32>)
+    })
+    .unwrap_err();
+    assert_eq!((e.column, e.text.as_str(), e.expected.as_str()), (8, "b", "a height"));
+
+    let e = aoc_parse::parse("12 left over", |s| s.unsigned::<u8>()).unwrap_err();
+    assert_eq!((e.column, e.text.as_str(), e.expected.as_str()), (3, " left over", "the end of the line"));
+
+    let e = aoc_parse::parse("300", |s| s.unsigned::<u8>()).unwrap_err();
+    assert_eq!((e.column, e.text.as_str(), e.expected.as_str()), (1, "300", "a smaller number"));
+
+    let e = aoc_parse::parse("", |s| s.identifier()).unwrap_err();
+    assert_eq!(e.to_string(), "line 1, column 1: expected a name, found nothing");
+}
@@ -0,0 +1,108 @@
+//! Accepted answers for our own puzzle inputs, kept next to each day in `answers.toml`:
+//!
+//! ```toml
+//! part1 = 409147
+//! part2 = 991
+//! ```
+//!
+//! `aoc verify` checks every day with an input against these, so refactors can't quietly change
+//! an answer.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Day, Part};
+use serde::Deserialize;
+
+/// A day's accepted answers. Either part may not be recorded yet.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+/// An expected answer. They're usually numbers, so they can be written without quotes.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Expected {
+    Text(String),
+    Number(i64),
+}
+
+/// How an answer compares with the accepted one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The accepted answer, and a [`diff`] from it.
+    Fail { want: String, diff: String },
+    /// Nothing is recorded for the part yet.
+    Unrecorded,
+}
+
+/// Where a day keeps its answers.
+pub fn path(root: &Path, day: &Day) -> PathBuf {
+    crate::day_dir(root, day).join("answers.toml")
+}
+
+/// Load the answers in `path`. A missing file just means nothing has been recorded yet.
+pub fn load(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(_) if !path.exists() => Ok(Answers::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<String> {
+        let expected = match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        };
+
+        expected.as_ref().map(Expected::to_string)
+    }
+
+    /// Check `got` against the accepted answer for `part`.
+    pub fn check(&self, part: Part, got: &str) -> Verdict {
+        match self.get(part) {
+            Some(want) if matches(&want, got) => Verdict::Pass,
+            Some(want) => Verdict::Fail { diff: diff(&want, got), want },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+/// Whether an answer matches the expected one, ignoring surrounding whitespace.
+pub fn matches(want: &str, got: &str) -> bool {
+    want.trim() == got.trim()
+}
+
+/// Expected vs. actual, one line at a time: `-` lines are wanted, `+` lines are what we got.
+pub fn diff(want: &str, got: &str) -> String {
+    let want: Vec<&str> = want.trim().lines().collect();
+    let got: Vec<&str> = got.trim().lines().collect();
+
+    (0..want.len().max(got.len()))
+        .flat_map(|i| match (want.get(i), got.get(i)) {
+            (Some(w), Some(g)) if w == g => vec![format!("  {}", w)],
+            (w, g) => {
+                let w = w.map(|w| format!("- {}", w));
+                let g = g.map(|g| format!("+ {}", g));
+                w.into_iter().chain(g).collect()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Expected::Text(ref s) => write!(f, "{}", s),
+            Expected::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
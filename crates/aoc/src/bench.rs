@@ -0,0 +1,166 @@
+//! Timing parse, part 1 and part 2 separately, and remembering past timings so a slower run stands
+//! out. History is one JSON record per line, appended after every `aoc bench`.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_core::{Day, ParseError, Part};
+use serde::{Deserialize, Serialize};
+
+/// Changes smaller than this are timer noise, whatever the percentage says.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// What's being timed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+/// Timings for one stage of one day.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub stage: Stage,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub runs: usize,
+}
+
+/// A [`Measurement`] as it's kept in the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub stage: String,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub runs: usize,
+}
+
+/// Run `day` on `input` `warmup` times untimed, then `runs` times timing each stage. Panics if
+/// `runs` is 0, as there's nothing to measure.
+pub fn measure(day: &Day, input: &str, warmup: usize, runs: usize) -> Result<Vec<Measurement>, ParseError> {
+    assert!(runs > 0, "no runs to measure");
+
+    for _ in 0..warmup {
+        let parsed = day.parse(input)?;
+        for part in Part::BOTH {
+            day.solve_part(&parsed, part);
+        }
+    }
+
+    let mut samples: Vec<(Stage, Vec<Duration>)> = vec![
+        (Stage::Parse, vec![]),
+        (Stage::Part(Part::One), vec![]),
+        (Stage::Part(Part::Two), vec![]),
+    ];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(input)?;
+        samples[0].1.push(start.elapsed());
+
+        for (i, part) in Part::BOTH.into_iter().enumerate() {
+            let start = Instant::now();
+            day.solve_part(&parsed, part);
+            samples[i + 1].1.push(start.elapsed());
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(stage, mut times)| {
+            times.sort();
+            Measurement {
+                stage,
+                median: times[times.len() / 2],
+                min: times[0],
+                max: times[times.len() - 1],
+                runs: times.len(),
+            }
+        })
+        .collect())
+}
+
+/// Where history goes unless `--history` says otherwise.
+pub fn default_history(root: &Path) -> PathBuf {
+    root.join("bench-history.jsonl")
+}
+
+/// Every record in the history file, oldest first. No file means no history yet.
+pub fn load_history(path: &Path) -> Result<Vec<Record>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(_) if !path.exists() => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| format!("{}:{}: {}", path.display(), i + 1, e))
+        })
+        .collect()
+}
+
+/// Add records to the end of the history file.
+pub fn append_history(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+
+    Ok(())
+}
+
+/// The most recent record for the same day and stage, if there is one.
+pub fn previous<'a>(history: &'a [Record], day: &Day, stage: Stage) -> Option<&'a Record> {
+    let stage = stage.to_string();
+    history.iter().rev().find(|r| r.year == day.year && r.day == day.day && r.stage == stage)
+}
+
+impl Measurement {
+    pub fn record(&self, day: &Day) -> Record {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+        Record {
+            timestamp,
+            year: day.year,
+            day: day.day,
+            stage: self.stage.to_string(),
+            median_ns: self.median.as_nanos() as u64,
+            min_ns: self.min.as_nanos() as u64,
+            runs: self.runs,
+        }
+    }
+
+    /// How much slower (positive) or faster (negative) this is than `previous`, in percent.
+    pub fn change(&self, previous: &Record) -> f64 {
+        let before = previous.median_ns.max(1) as f64;
+        (self.median.as_nanos() as f64 - before) / before * 100.0
+    }
+
+    /// Whether this is more than `threshold` percent slower than `previous`, and by more than the
+    /// [`NOISE_FLOOR`].
+    pub fn regressed(&self, previous: &Record, threshold: f64) -> bool {
+        let before = Duration::from_nanos(previous.median_ns);
+        self.change(previous) > threshold && self.median.saturating_sub(before) > NOISE_FLOOR
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::bench;
+use aoc::{format_duration, inputs, registry, Selection};
+use aoc_core::Day;
+
+pub struct Options {
+    pub runs: usize,
+    pub warmup: usize,
+    /// Percent slower than the previous run that counts as a regression.
+    pub threshold: f64,
+    pub history: Option<PathBuf>,
+    pub save: bool,
+}
+
+pub fn bench(selection: Selection, options: Options) -> bool {
+    let root = aoc::root();
+    let history_path = options.history.clone().unwrap_or_else(|| bench::default_history(&root));
+    let history = match bench::load_history(&history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Couldn't read bench history: {}", e);
+            return false;
+        }
+    };
+
+    let days: Vec<Day> = registry::days().into_iter().filter(|d| selection.contains(d)).collect();
+    if days.is_empty() {
+        eprintln!("No solutions match");
+        return false;
+    }
+
+    let mut rows = vec![];
+    let mut records = vec![];
+    let mut regressions = 0;
+    let mut ok = true;
+
+    for day in days {
+        let text = match inputs::find(&root, &day).map(fs::read_to_string) {
+            Some(Ok(text)) => text,
+            Some(Err(e)) => {
+                rows.push(note(&day, e));
+                ok = false;
+                continue;
+            }
+            None => {
+                rows.push(note(&day, "no input"));
+                continue;
+            }
+        };
+
+        let measurements = match bench::measure(&day, &text, options.warmup, options.runs) {
+            Ok(measurements) => measurements,
+            Err(e) => {
+                rows.push(note(&day, e));
+                ok = false;
+                continue;
+            }
+        };
+
+        for m in measurements {
+            let change = match bench::previous(&history, &day, m.stage) {
+                Some(previous) => {
+                    let change = m.change(previous);
+                    if m.regressed(previous, options.threshold) {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => String::new(),
+            };
+
+            rows.push(vec![
+                day.to_string(),
+                m.stage.to_string(),
+                format_duration(m.median),
+                format_duration(m.min),
+                format_duration(m.max),
+                m.runs.to_string(),
+                change,
+            ]);
+            records.push(m.record(&day));
+        }
+    }
+
+    super::print_table(&["DAY", "STAGE", "MEDIAN", "MIN", "MAX", "RUNS", "CHANGE"], &rows, &[2, 3, 4, 5]);
+
+    if options.save && !records.is_empty() {
+        if let Err(e) = bench::append_history(&history_path, &records) {
+            eprintln!("Couldn't save bench history to {}: {}", history_path.display(), e);
+            ok = false;
+        }
+    }
+
+    if regressions > 0 {
+        println!(
+            "\n{} stage{} more than {}% slower than the previous run",
+            regressions,
+            if regressions == 1 { "" } else { "s" },
+            options.threshold
+        );
+    }
+
+    ok && regressions == 0
+}
+
+/// A row for a day that couldn't be timed.
+fn note<T: ToString>(day: &Day, note: T) -> Vec<String> {
+    let mut row = vec![day.to_string(), "-".to_string()];
+    row.extend((0..4).map(|_| String::new()));
+    row.push(note.to_string());
+    row
+}
@@ -0,0 +1,51 @@
+use aoc::{registry, DayId};
+use aoc_core::Drawing;
+
+use super::run::{resolve, Source};
+
+/// Print a picture of `id`'s puzzle worked through with the given input, for days that can draw
+/// one.
+pub fn draw(id: DayId, source: Source, drawing: Drawing) -> bool {
+    let day = match registry::find(id.year, id.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("No solution for {}", id);
+            return false;
+        }
+    };
+
+    let input = match resolve(&day, source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Couldn't read {}: {}", input, e);
+            return false;
+        }
+    };
+
+    let parsed = match day.parse(&text) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}: {}", input, e);
+            eprintln!("{}", e.annotate(&text));
+            return false;
+        }
+    };
+
+    match day.draw(&parsed, drawing) {
+        Some(picture) => {
+            println!("{}", picture.trim_end());
+            true
+        }
+        None => {
+            eprintln!("{} can't draw itself", id);
+            false
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc::{registry, DayId};
+
+/// Print a random input for `id`. Without a seed one is made up, and printed on stderr so the
+/// same input can be made again.
+pub fn generate(id: DayId, seed: Option<u64>, size: Option<usize>) -> bool {
+    let day = match registry::find(id.year, id.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("No solution for {}", id);
+            return false;
+        }
+    };
+
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+        eprintln!("seed {}", seed);
+        seed
+    });
+
+    match day.generate(seed, size) {
+        Some(input) => {
+            println!("{}", input.trim_end());
+            true
+        }
+        None => {
+            eprintln!("{} has no input generator", id);
+            false
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! One module per `aoc` subcommand (and `run_all` for `aoc run --all`). Each returns whether it
+//! succeeded, for the exit status.
+
+pub mod bench;
+pub mod draw;
+pub mod generate;
+pub mod new;
+pub mod run;
+pub mod run_all;
+pub mod verify;
+
+/// First line of an answer, so multi-line answers fit in a table.
+fn summary(answer: &str) -> String {
+    let mut lines = answer.trim().lines();
+    let first = lines.next().unwrap_or("");
+
+    match lines.count() {
+        0 => first.to_string(),
+        more => format!("{} (+{} lines)", first, more),
+    }
+}
+
+/// Print rows in aligned columns under `header`. Columns listed in `right` are right-aligned.
+fn print_table(header: &[&str], rows: &[Vec<String>], right: &[usize]) {
+    let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+    let mut widths = vec![0; header.len()];
+
+    for row in std::iter::once(&header).chain(rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| {
+                if right.contains(&i) {
+                    format!("{:>1$}", cell, width)
+                } else if i + 1 == row.len() {
+                    cell.to_string()
+                } else {
+                    format!("{:<1$}", cell, width)
+                }
+            })
+            .collect();
+
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
@@ -0,0 +1,21 @@
+use aoc::{scaffold, DayId};
+
+pub fn new(id: DayId) -> bool {
+    let root = aoc::root();
+
+    let files = match scaffold::create(&root, id) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    println!("Created {}", id);
+    for path in files {
+        println!("\t{}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    println!("\nhttp://adventofcode.com/{}/day/{}", id.year, id.day);
+
+    true
+}
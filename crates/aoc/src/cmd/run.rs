@@ -0,0 +1,164 @@
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use aoc::inputs::{self, InputError};
+use aoc::json::{answer_record, error_record};
+use aoc::{registry, DayId};
+use aoc_core::viz::{self, Asciicast, Recorder, Silent, Terminal, Throttle};
+use aoc_2016_day_2::Keypad;
+use aoc_core::{print_answer, Day, Input, Part, Solution};
+
+use crate::{Format, Viz};
+
+/// Where `aoc run` reads its input from.
+pub enum Source {
+    /// The day's real input. STDIN is only read when asked for (`--input -`).
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// A named sample, or the only one when there's no name.
+    Sample(Option<String>),
+}
+
+/// How `aoc run` shows the day's animation, if it has one.
+pub struct Animation {
+    pub viz: Viz,
+    /// Record to this file instead of drawing.
+    pub record: Option<PathBuf>,
+    /// Limits on what's recorded.
+    pub fps: u32,
+    pub max_frames: Option<usize>,
+}
+
+/// With `keypad`, a file of keypad art and the button to start on, 2016/2's moves are typed on
+/// that instead of solving the two parts.
+pub fn run(
+    id: DayId,
+    source: Source,
+    part: Option<u8>,
+    format: Format,
+    animation: Animation,
+    keypad: Option<(PathBuf, char)>,
+) -> bool {
+    let day = match registry::find(id.year, id.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("No solution for {}", id);
+            return false;
+        }
+    };
+
+    let input = match resolve(&day, source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Couldn't read {}: {}", input, e);
+            return false;
+        }
+    };
+
+    if let Err(e) = animate(animation) {
+        eprintln!("{}", e);
+        return false;
+    }
+
+    if let Some((path, start)) = keypad {
+        return type_on(&path, start, &input, &text);
+    }
+
+    let parts = match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let parsed = match day.parse(&text) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}: {}", input, e);
+            eprintln!("{}", e.annotate(&text));
+            if format == Format::Json {
+                println!("{}", error_record(&day, &e));
+            }
+            return false;
+        }
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = day.solve_part(&parsed, part);
+        let elapsed = start.elapsed();
+
+        match format {
+            Format::Text => print_answer(part.number(), answer),
+            Format::Json => println!("{}", answer_record(&day, part, &answer, elapsed)),
+        }
+    }
+
+    true
+}
+
+/// Type the 2016/2 moves in `text`, read from `input`, on the keypad drawn in the file at `path`.
+fn type_on(path: &Path, start: char, input: &Input, text: &str) -> bool {
+    let mut keypad = match aoc::keypad::load(path, start) {
+        Ok(keypad) => keypad,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let lines = match Keypad::parse(text) {
+        Ok(lines) => lines,
+        Err(e) => {
+            eprintln!("error: {}: {}", input, e);
+            eprintln!("{}", e.annotate(text));
+            return false;
+        }
+    };
+
+    println!("Code: {}", keypad.code(&lines));
+    true
+}
+
+/// Point the day's animation, if it has one, at the chosen backend.
+fn animate(animation: Animation) -> Result<(), String> {
+    if let Some(path) = animation.record {
+        let throttle = Throttle::new(animation.fps, animation.max_frames);
+        let failed = |e| format!("Couldn't record to {}: {}", path.display(), e);
+        if path.extension().is_some_and(|ext| ext == "cast") {
+            viz::set(Asciicast::create(&path, throttle).map_err(failed)?);
+        } else {
+            viz::set(Recorder::create(&path, throttle).map_err(failed)?);
+        }
+        return Ok(());
+    }
+
+    match animation.viz {
+        Viz::Auto if io::stderr().is_terminal() => viz::set(Terminal::new()),
+        Viz::Auto | Viz::None => viz::set(Silent),
+        Viz::Terminal => viz::set(Terminal::new()),
+        #[cfg(feature = "ncurses")]
+        Viz::Ncurses => viz::set(viz::Ncurses::new()),
+    }
+
+    Ok(())
+}
+
+/// Where to read `day`'s input from.
+pub fn resolve(day: &Day, source: Source) -> Result<Input, InputError> {
+    let root = aoc::root();
+
+    match source {
+        Source::File(path) => Ok(Input::File(path)),
+        Source::Stdin => Ok(Input::Stdin),
+        Source::Sample(name) => inputs::sample(&root, day, name.as_deref()).map(Input::File),
+        Source::Default => inputs::resolve(&root, day).map(Input::File),
+    }
+}
@@ -0,0 +1,51 @@
+//! `aoc run --all`: every registered day at once, on a pool of threads (see [`aoc::solve_all`]).
+
+use std::fs;
+
+use aoc::solve_all::{self, Options, Row, Status};
+use aoc::{format_duration, inputs, registry};
+use aoc_core::viz::{self, Silent};
+
+use super::summary;
+
+pub fn run_all(options: Options) -> bool {
+    let root = aoc::root();
+
+    // Nothing gets to draw while days run side by side.
+    viz::set(Silent);
+
+    let rows = solve_all::solve_all(&registry::days(), &options, |day| match inputs::find(&root, day) {
+        Some(path) => fs::read_to_string(&path).map_err(|e| Row::day(*day, Status::Error, format!("{}: {}", path.display(), e))),
+        None => Err(Row::day(*day, Status::Missing, "no input")),
+    });
+    print_table(&rows);
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} answers, {} missing, {} errors, {} panics, {} timeouts",
+        count(Status::Ok),
+        count(Status::Missing),
+        count(Status::Error),
+        count(Status::Panic),
+        count(Status::Timeout)
+    );
+
+    count(Status::Error) + count(Status::Panic) + count(Status::Timeout) == 0
+}
+
+fn print_table(rows: &[Row]) {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|r| {
+            vec![
+                r.day.to_string(),
+                r.part.map_or("-".to_string(), |p| p.to_string()),
+                summary(&r.answer),
+                r.time.map_or(String::new(), format_duration),
+                r.status.to_string(),
+            ]
+        })
+        .collect();
+
+    super::print_table(&["DAY", "PART", "ANSWER", "TIME", "STATUS"], &cells, &[3]);
+}
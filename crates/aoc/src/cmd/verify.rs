@@ -0,0 +1,148 @@
+use std::fmt;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc::answers::{self, Verdict};
+use aoc::{format_duration, inputs, registry, Selection};
+use aoc_core::{Day, Part};
+
+use super::summary;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+/// One line of the table.
+struct Row {
+    day: Day,
+    part: Option<Part>,
+    status: Status,
+    parse: Option<Duration>,
+    solve: Option<Duration>,
+    note: String,
+}
+
+pub fn verify(selection: Selection) -> bool {
+    let root = aoc::root();
+    let days: Vec<Day> = registry::days().into_iter().filter(|d| selection.contains(d)).collect();
+
+    if days.is_empty() {
+        eprintln!("No solutions match");
+        return false;
+    }
+
+    let mut rows = vec![];
+    let mut diffs = vec![];
+
+    for day in days {
+        let answers = match answers::load(&answers::path(&root, &day)) {
+            Ok(answers) => answers,
+            Err(e) => {
+                rows.push(Row::day(day, Status::Error, e));
+                continue;
+            }
+        };
+
+        let path = match inputs::find(&root, &day) {
+            Some(path) => path,
+            None => {
+                rows.push(Row::day(day, Status::Missing, "no input"));
+                continue;
+            }
+        };
+
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                rows.push(Row::day(day, Status::Error, format!("{}: {}", path.display(), e)));
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let parsed = match day.parse(&text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                rows.push(Row::day(day, Status::Error, format!("{}: {}", path.display(), e)));
+                continue;
+            }
+        };
+        let parse = start.elapsed();
+
+        for part in Part::BOTH {
+            let start = Instant::now();
+            let answer = day.solve_part(&parsed, part);
+            let solve = start.elapsed();
+
+            let (status, note) = match answers.check(part, answer.value()) {
+                Verdict::Pass => (Status::Pass, summary(answer.value())),
+                Verdict::Fail { want, diff } => {
+                    diffs.push(format!("{} part {}\n{}", day, part, diff));
+                    (Status::Fail, format!("expected {}", summary(&want)))
+                }
+                Verdict::Unrecorded => (Status::Missing, format!("no answer recorded, got {}", summary(answer.value()))),
+            };
+
+            rows.push(Row { day, part: Some(part), status, parse: Some(parse), solve: Some(solve), note });
+        }
+    }
+
+    print_table(&rows);
+
+    for diff in &diffs {
+        println!("\n{}", diff);
+    }
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing, {} errors",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Error)
+    );
+
+    count(Status::Fail) == 0 && count(Status::Error) == 0
+}
+
+impl Row {
+    /// A row for a whole day that couldn't be run.
+    fn day<T: Into<String>>(day: Day, status: Status, note: T) -> Row {
+        Row { day, part: None, status, parse: None, solve: None, note: note.into() }
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|r| {
+            vec![
+                r.day.to_string(),
+                r.part.map_or("-".to_string(), |p| p.to_string()),
+                r.status.to_string(),
+                r.parse.map_or(String::new(), format_duration),
+                r.solve.map_or(String::new(), format_duration),
+                r.note.clone(),
+            ]
+        })
+        .collect();
+
+    super::print_table(&["DAY", "PART", "STATUS", "PARSE", "SOLVE", "DETAIL"], &cells, &[3, 4]);
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "error",
+        };
+
+        write!(f, "{}", s)
+    }
+}
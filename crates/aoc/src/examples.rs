@@ -0,0 +1,113 @@
+//! Puzzle examples, kept as data next to each day in `examples.toml`:
+//!
+//! ```toml
+//! [[example]]
+//! input = "R2, R2, R2"
+//! part1 = 2
+//!
+//! [[example]]
+//! name = "the sample from the puzzle text"
+//! file = "day-7.sample"
+//! part1 = 2
+//! part2 = 3
+//! ```
+//!
+//! Each example has either an inline `input` or a `file` relative to the day's directory, never
+//! both, and the expected answer for one or both parts. Parts without an expectation aren't run.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Day, Part};
+use serde::Deserialize;
+
+use crate::answers::{self, Expected};
+
+/// One puzzle example and the answers it should produce.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub name: Option<String>,
+    input: Option<String>,
+    file: Option<PathBuf>,
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Deserialize)]
+struct ExamplesFile {
+    #[serde(default)]
+    example: Vec<Example>,
+}
+
+/// Where a day keeps its examples.
+pub fn path(root: &Path, day: &Day) -> PathBuf {
+    crate::day_dir(root, day).join("examples.toml")
+}
+
+/// Load every example in `path`. A missing file just means the day has no examples yet.
+pub fn load(path: &Path) -> Result<Vec<Example>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(_) if !path.exists() => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+
+    let file: ExamplesFile =
+        toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    file.example
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut example)| {
+            let wrong = |problem| format!("{}: example {} {}", path.display(), i + 1, problem);
+
+            match (&example.input, &example.file) {
+                (Some(_), None) => {}
+                (None, Some(file)) => {
+                    let file = dir.join(file);
+                    let input = fs::read_to_string(&file)
+                        .map_err(|e| format!("{}: {}", file.display(), e))?;
+                    example.input = Some(input);
+                }
+                (Some(_), Some(_)) => return Err(wrong("has both `input` and `file`; it needs just one")),
+                (None, None) => return Err(wrong("needs `input` or `file`")),
+            }
+
+            Ok(example)
+        })
+        .collect()
+}
+
+impl Example {
+    pub fn input(&self) -> &str {
+        self.input.as_deref().unwrap_or("")
+    }
+
+    /// The parts this example has an answer for.
+    pub fn expected(&self) -> Vec<(Part, String)> {
+        let mut expected = vec![];
+        if let Some(ref e) = self.part1 { expected.push((Part::One, e.to_string())); }
+        if let Some(ref e) = self.part2 { expected.push((Part::Two, e.to_string())); }
+        expected
+    }
+
+    /// Run the example through `day`, returning a description of every part that came out wrong.
+    pub fn check(&self, day: &Day) -> Vec<String> {
+        let expected = self.expected();
+        let parts: Vec<Part> = expected.iter().map(|&(part, _)| part).collect();
+
+        let answers = match day.solve(self.input(), &parts) {
+            Ok(answers) => answers,
+            Err(e) => return vec![format!("couldn't parse input\n{}\n{}", e, e.annotate(self.input()))],
+        };
+
+        expected
+            .iter()
+            .zip(answers)
+            .filter(|((_, want), (_, got))| !answers::matches(want, got.value()))
+            .map(|((part, want), (_, got))| format!("part {}\n{}", part, answers::diff(want, got.value())))
+            .collect()
+    }
+}
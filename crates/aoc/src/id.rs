@@ -0,0 +1,59 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::Day;
+
+/// A year and day, as typed on the command line: `2017/14` or `2017/day-14`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u8,
+}
+
+/// Some days: everything, a whole year (`2017`), or a single day (`2017/14`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+impl Selection {
+    pub fn contains(&self, day: &Day) -> bool {
+        self.year.is_none_or(|year| year == day.year) && self.day.is_none_or(|d| d == day.day)
+    }
+}
+
+impl FromStr for DayId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DayId, String> {
+        let expected = || format!("expected YEAR/DAY like 2017/14, got `{}`", s);
+        let (year, day) = s.split_once('/').ok_or_else(expected)?;
+        let day = day.trim_start_matches("day-");
+
+        Ok(DayId {
+            year: year.parse().map_err(|_| expected())?,
+            day: day.parse().map_err(|_| expected())?,
+        })
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Selection, String> {
+        if s.contains('/') {
+            let id: DayId = s.parse()?;
+            return Ok(Selection { year: Some(id.year), day: Some(id.day) });
+        }
+
+        let year = s.parse().map_err(|_| format!("expected YEAR or YEAR/DAY, got `{}`", s))?;
+        Ok(Selection { year: Some(year), day: None })
+    }
+}
+
+impl fmt::Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
@@ -0,0 +1,214 @@
+//! Finding a day's puzzle input and samples.
+//!
+//! The real input for, say, 2017 day 7 is the first of these that exists, with `day-07` tried
+//! after `day-7` each time:
+//!
+//! ```text
+//! 2017/day-7.input
+//! 2017/day-7.txt
+//! 2017/day-7/day-7.input
+//! 2017/day-7/day-7.txt
+//! ```
+//!
+//! If none does and `AOC_SESSION` holds an adventofcode.com session cookie, the input is
+//! downloaded once and cached at `2017/day-7.input`.
+//!
+//! Samples are small inputs, usually from the puzzle text, picked with `--sample NAME`:
+//!
+//! ```text
+//! 2017/day-7/samples/NAME.txt     (or NAME.input) the usual place
+//! 2017/day-7/NAME.sample          older days keep them loose in the crate
+//! 2017/day-7-sample.input         advent.rb's `-t` sample, named "sample"
+//! ```
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::Day;
+
+/// A named sample input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Why an input couldn't be found.
+#[derive(Debug)]
+pub enum InputError {
+    /// No real input anywhere we looked.
+    Missing { checked: Vec<PathBuf> },
+    /// No sample called `name`; `available` lists the ones there are.
+    NoSample { name: Option<String>, available: Vec<Sample> },
+    /// The input wasn't on disk and downloading it failed.
+    Download(String),
+}
+
+/// Every path the input for `day` might be at, most likely first.
+pub fn candidates(root: &Path, day: &Day) -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    for dir in dirs(root, day) {
+        for name in names(day) {
+            for ext in ["input", "txt"] {
+                push_new(&mut paths, dir.join(format!("{}.{}", name, ext)));
+            }
+        }
+    }
+
+    paths
+}
+
+/// The first candidate that exists.
+pub fn find(root: &Path, day: &Day) -> Option<PathBuf> {
+    candidates(root, day).into_iter().find(|path| path.is_file())
+}
+
+/// Every sample for `day`, sorted by name.
+pub fn samples(root: &Path, day: &Day) -> Vec<Sample> {
+    let day_dir = crate::day_dir(root, day);
+    let mut samples = vec![];
+
+    for path in files_in(&day_dir.join("samples")) {
+        if matches!(extension(&path), Some("txt") | Some("input")) {
+            samples.push(Sample { name: stem(&path), path });
+        }
+    }
+
+    for path in files_in(&day_dir) {
+        if extension(&path) == Some("sample") {
+            samples.push(Sample { name: stem(&path), path });
+        }
+    }
+
+    for dir in dirs(root, day) {
+        for name in names(day) {
+            for ext in ["input", "txt"] {
+                let path = dir.join(format!("{}-sample.{}", name, ext));
+                if path.is_file() && !samples.iter().any(|s| s.path == path) {
+                    samples.push(Sample { name: "sample".to_string(), path });
+                }
+            }
+        }
+    }
+
+    samples.sort_by(|a, b| a.name.cmp(&b.name));
+    samples
+}
+
+/// The sample called `name`, or with no name, the day's only sample (or the one called "sample").
+pub fn sample(root: &Path, day: &Day, name: Option<&str>) -> Result<PathBuf, InputError> {
+    let available = samples(root, day);
+
+    let found = match name {
+        Some(name) => available.iter().find(|s| s.name == name),
+        None if available.len() == 1 => available.first(),
+        None => available.iter().find(|s| s.name == "sample"),
+    };
+
+    match found {
+        Some(sample) => Ok(sample.path.clone()),
+        None => Err(InputError::NoSample { name: name.map(String::from), available }),
+    }
+}
+
+/// The real input for `day`, downloading it if it isn't on disk and `AOC_SESSION` is set.
+pub fn resolve(root: &Path, day: &Day) -> Result<PathBuf, InputError> {
+    if let Some(path) = find(root, day) {
+        return Ok(path);
+    }
+
+    match env::var("AOC_SESSION") {
+        Ok(session) if !session.trim().is_empty() => download(root, day, session.trim()),
+        _ => Err(InputError::Missing { checked: candidates(root, day) }),
+    }
+}
+
+/// Fetch the input from adventofcode.com and cache it where [`find`] looks first.
+fn download(root: &Path, day: &Day, session: &str) -> Result<PathBuf, InputError> {
+    let url = format!("https://adventofcode.com/{}/day/{}/input", day.year, day.day);
+    // ureq's errors already name the URL.
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", "aoc runner")
+        .call()
+        .map_err(|e| InputError::Download(e.to_string()))?;
+    let text = response.into_string().map_err(|e| InputError::Download(format!("{}: {}", url, e)))?;
+
+    let path = candidates(root, day).remove(0);
+    fs::write(&path, text).map_err(|e| InputError::Download(format!("{}: {}", path.display(), e)))?;
+
+    Ok(path)
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputError::Missing { ref checked } => {
+                writeln!(f, "Couldn't find input, checked:")?;
+                for path in checked {
+                    writeln!(f, "\t{}", path.display())?;
+                }
+                write!(f, "Set AOC_SESSION to your adventofcode.com session cookie to download it.")
+            }
+            InputError::NoSample { ref name, ref available } => {
+                if available.is_empty() {
+                    return write!(f, "This day has no samples");
+                }
+
+                match *name {
+                    Some(ref name) => writeln!(f, "No sample called \"{}\", there are:", name)?,
+                    None => writeln!(f, "Pick a sample with --sample NAME:")?,
+                }
+
+                let lines: Vec<String> = available
+                    .iter()
+                    .map(|s| format!("\t{}\t{}", s.name, s.path.display()))
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            InputError::Download(ref e) => write!(f, "Couldn't download input: {}", e),
+        }
+    }
+}
+
+/// `2017`, `2017/day-7`, `2017/day-07`
+fn dirs(root: &Path, day: &Day) -> Vec<PathBuf> {
+    let year = root.join(day.year.to_string());
+    let mut dirs = vec![year.clone()];
+    for name in names(day) {
+        push_new(&mut dirs, year.join(name));
+    }
+    dirs
+}
+
+/// `day-7`, `day-07`
+fn names(day: &Day) -> Vec<String> {
+    let mut names = vec![];
+    push_new(&mut names, format!("day-{}", day.day));
+    push_new(&mut names, format!("day-{:02}", day.day));
+    names
+}
+
+fn push_new<T: PartialEq>(v: &mut Vec<T>, item: T) {
+    if !v.contains(&item) {
+        v.push(item);
+    }
+}
+
+fn files_in(dir: &Path) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(Result::ok).map(|e| e.path()).filter(|p| p.is_file()).collect(),
+        Err(_) => vec![],
+    }
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|e| e.to_str())
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem().map_or(String::new(), |s| s.to_string_lossy().into_owned())
+}
@@ -0,0 +1,58 @@
+//! `aoc run --format json`: one object per line, for each answer or for input that couldn't be
+//! parsed.
+
+use std::time::Duration;
+
+use aoc_core::{Answer, Day, ParseError, Part};
+use serde::Serialize;
+
+/// One answer.
+#[derive(Serialize)]
+struct AnswerRecord<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    /// Time taken by the part itself, not counting parsing the input.
+    elapsed_ns: u64,
+    detail: Option<&'a str>,
+}
+
+/// Input that couldn't be parsed.
+#[derive(Serialize)]
+struct ErrorRecord<'a> {
+    year: u16,
+    day: u8,
+    error: String,
+    line: usize,
+    column: usize,
+    text: &'a str,
+    expected: &'a str,
+}
+
+pub fn answer_record(day: &Day, part: Part, answer: &Answer, elapsed: Duration) -> String {
+    let record = AnswerRecord {
+        year: day.year,
+        day: day.day,
+        part: part.number(),
+        answer: answer.value(),
+        elapsed_ns: elapsed.as_nanos() as u64,
+        detail: answer.detail(),
+    };
+
+    serde_json::to_string(&record).expect("answers always serialize")
+}
+
+pub fn error_record(day: &Day, e: &ParseError) -> String {
+    let record = ErrorRecord {
+        year: day.year,
+        day: day.day,
+        error: e.to_string(),
+        line: e.line,
+        column: e.column,
+        text: &e.text,
+        expected: &e.expected,
+    };
+
+    serde_json::to_string(&record).expect("errors always serialize")
+}
@@ -0,0 +1,19 @@
+//! `aoc run 2016/2 --keypad`: 2016/2's moves typed on a keypad drawn in a file, rather than on
+//! the two the puzzle comes with.
+
+use std::fs;
+use std::path::Path;
+
+use aoc_2016_day_2::Keypad;
+
+use crate::DayId;
+
+/// The only day `--keypad` goes with.
+pub const DAY: DayId = DayId { year: 2016, day: 2 };
+
+/// The keypad drawn in the file at `path`, in the art [`Keypad::from_art`] reads, starting on the
+/// `start` button.
+pub fn load(path: &Path, start: char) -> Result<Keypad, String> {
+    let art = fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    Keypad::from_art(&art, start).map_err(|e| format!("{}: {}", path.display(), e))
+}
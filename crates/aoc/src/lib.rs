@@ -0,0 +1,46 @@
+//! Everything behind the `aoc` binary: the table of solved days, where their inputs live, and the
+//! examples and answers they're checked against, the scaffolding for new days, and solving them
+//! all at once.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::Day;
+
+mod id;
+
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod inputs;
+pub mod json;
+pub mod keypad;
+pub mod registry;
+pub mod scaffold;
+pub mod solve_all;
+
+pub use id::{DayId, Selection};
+
+/// The repository checkout this binary was built from, where the `<year>/day-<n>` directories live.
+pub fn root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.ancestors().nth(2).unwrap_or(manifest_dir).to_path_buf()
+}
+
+/// A day's own directory, e.g. `2017/day-14`.
+pub fn day_dir(root: &Path, day: &Day) -> PathBuf {
+    root.join(day.year.to_string()).join(format!("day-{}", day.day))
+}
+
+/// Short human-friendly duration: `850µs`, `12.3ms`, `1.20s`.
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.1}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
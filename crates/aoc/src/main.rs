@@ -0,0 +1,243 @@
+//! `aoc` runs any solved Advent of Code day from one binary.
+//!
+//!     aoc run 2017/14
+//!     aoc run 2016/day-6 --sample
+//!     aoc run 2016/day-2 --part 2 --input 2016/day-2.input
+//!     aoc run 2016/1 --format json < 2016/day-1.input
+//!     aoc run 2016/2 --keypad phone.txt --start 0
+//!     aoc run 2016/8 --viz none --record screen.txt
+//!     aoc run 2016/5 --record hacker.cast --fps 20 --max-frames 2000
+//!     aoc run --all --jobs 4 --timeout 30
+//!     aoc verify 2017
+//!     aoc bench 2017/15 --runs 10
+//!     aoc gen 2017/7 --seed 1 --size 5000
+//!     aoc draw 2016/1 --svg > walk.svg
+//!     aoc new 2018/7
+
+mod cmd;
+
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use aoc::{DayId, Selection};
+use aoc_core::Drawing;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Pt 1: 241 (Bunny HQ is 241 blocks away)`
+    Text,
+    /// One JSON object per answer: `{"year", "day", "part", "answer", "elapsed_ns", "detail"}`
+    Json,
+}
+
+/// Where days that animate draw their frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Viz {
+    /// The terminal if stderr is one, otherwise nowhere.
+    Auto,
+    /// In place on stderr, with ANSI escapes.
+    Terminal,
+    /// With ncurses.
+    #[cfg(feature = "ncurses")]
+    Ncurses,
+    /// Nowhere.
+    None,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with `--all`.
+    Run {
+        /// Which puzzle, e.g. `2017/14` or `2017/day-14`.
+        #[arg(required_unless_present = "all")]
+        day: Option<DayId>,
+
+        /// Solve every registered day with an input, several at once, and print a table.
+        #[arg(long, conflicts_with_all = ["day", "input", "sample", "part", "format", "record"])]
+        all: bool,
+
+        /// With `--all`, how many days to run at once. Defaults to the number of CPUs.
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// With `--all`, give up on a day after this many seconds. Defaults to 120.
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u64>,
+
+        /// Read the puzzle input from this file (`-` for STDIN) instead of the day's own input.
+        #[arg(short, long, conflicts_with = "sample")]
+        input: Option<PathBuf>,
+
+        /// Run a sample instead of the real input. NAME can be left out if there's only one.
+        #[arg(short = 't', long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+        sample: Option<String>,
+
+        /// Only solve this part.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Output format.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Where to draw animations.
+        #[arg(long, value_enum, default_value_t = Viz::Auto)]
+        viz: Viz,
+
+        /// Write the animation to this file instead of drawing it: an asciicast v2 recording for
+        /// `asciinema play` if it ends in `.cast`, otherwise plain text frames.
+        #[arg(long, value_name = "PATH")]
+        record: Option<PathBuf>,
+
+        /// Most frames a second to record, 0 for every frame.
+        #[arg(long, default_value_t = 30, requires = "record")]
+        fps: u32,
+
+        /// Most frames to record in all. The last frame is always kept.
+        #[arg(long, value_name = "N", requires = "record", value_parser = clap::value_parser!(u64).range(1..))]
+        max_frames: Option<u64>,
+
+        /// 2016/2 only: type the moves on the keypad drawn in this file, one row of buttons per
+        /// line with spaces for gaps, instead of the puzzle's two.
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "part", "format"])]
+        keypad: Option<PathBuf>,
+
+        /// The button `--keypad` starts on.
+        #[arg(long, value_name = "CHAR", default_value_t = '5', requires = "keypad")]
+        start: char,
+    },
+
+    /// Time parse, part 1 and part 2, and compare with the previous run.
+    Bench {
+        /// A year (`2017`) or day (`2017/14`). Everything by default.
+        selection: Option<Selection>,
+
+        /// Timed runs of each day.
+        #[arg(short, long, default_value_t = 5, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        runs: usize,
+
+        /// Untimed runs before timing starts.
+        #[arg(short, long, default_value_t = 1)]
+        warmup: usize,
+
+        /// Flag stages more than this many percent slower than last time.
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// History file to compare with and append to. Defaults to `bench-history.jsonl`.
+        #[arg(long)]
+        history: Option<PathBuf>,
+
+        /// Compare with history, but don't add this run to it.
+        #[arg(long)]
+        no_save: bool,
+    },
+
+    /// Print a picture of a day's puzzle worked through, for days that can draw one.
+    Draw {
+        /// Which puzzle, e.g. `2016/1`.
+        day: DayId,
+
+        /// Read the puzzle input from this file (`-` for STDIN) instead of the day's own input.
+        #[arg(short, long, conflicts_with = "sample")]
+        input: Option<PathBuf>,
+
+        /// Draw a sample instead of the real input. NAME can be left out if there's only one.
+        #[arg(short = 't', long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+        sample: Option<String>,
+
+        /// Draw an SVG image instead of ASCII art.
+        #[arg(long)]
+        svg: bool,
+    },
+
+    /// Print a random input for a day, for stress tests and benchmarks.
+    Gen {
+        /// Which puzzle, e.g. `2017/7`.
+        day: DayId,
+
+        /// The same seed always gives the same input. Made up (and printed on stderr) if left out.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// How big an input to make; what it counts depends on the day. About the size of a real
+        /// input if left out.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+
+    /// Start a new day: a crate with the `Solution` boilerplate, registered with the runner.
+    New {
+        /// Which puzzle, e.g. `2018/7`.
+        day: DayId,
+    },
+
+    /// Check solutions against the answers recorded in each day's `answers.toml`.
+    Verify {
+        /// A year (`2017`) or day (`2017/14`). Everything by default.
+        selection: Option<Selection>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let ok = match cli.command {
+        // `requires = "all"` can't tell a flag left off from one set to false.
+        Command::Run { all: false, jobs, timeout, .. } if jobs.is_some() || timeout.is_some() => Cli::command()
+            .error(ErrorKind::ArgumentConflict, "--jobs and --timeout only go with --all")
+            .exit(),
+        Command::Run { day: Some(day), keypad: Some(_), .. } if day != aoc::keypad::DAY => Cli::command()
+            .error(ErrorKind::ArgumentConflict, format!("--keypad only goes with {}", aoc::keypad::DAY))
+            .exit(),
+        Command::Run { all: true, jobs, timeout, .. } => {
+            let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            let options = aoc::solve_all::Options { jobs, timeout: Duration::from_secs(timeout.unwrap_or(120)) };
+            cmd::run_all::run_all(options)
+        }
+        Command::Run { day: Some(day), input, sample, part, format, viz, record, fps, max_frames, keypad, start, .. } => {
+            let animation = cmd::run::Animation { viz, record, fps, max_frames: max_frames.map(|n| n as usize) };
+            let keypad = keypad.map(|path| (path, start));
+            cmd::run::run(day, source(input, sample), part, format, animation, keypad)
+        }
+        Command::Run { day: None, .. } => unreachable!("clap requires a day without --all"),
+        Command::Bench { selection, runs, warmup, threshold, history, no_save } => {
+            let options = cmd::bench::Options { runs, warmup, threshold, history, save: !no_save };
+            cmd::bench::bench(selection.unwrap_or_default(), options)
+        }
+        Command::Draw { day, input, sample, svg } => {
+            let drawing = if svg { Drawing::Svg } else { Drawing::Text };
+            cmd::draw::draw(day, source(input, sample), drawing)
+        }
+        Command::Gen { day, seed, size } => cmd::generate::generate(day, seed, size),
+        Command::New { day } => cmd::new::new(day),
+        Command::Verify { selection } => cmd::verify::verify(selection.unwrap_or_default()),
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+/// Where to read the input from, given `--input` and `--sample`.
+fn source(input: Option<PathBuf>, sample: Option<String>) -> cmd::run::Source {
+    match (input, sample) {
+        (Some(path), _) if path.as_os_str() == "-" => cmd::run::Source::Stdin,
+        (Some(path), _) => cmd::run::Source::File(path),
+        (None, Some(name)) if name.is_empty() => cmd::run::Source::Sample(None),
+        (None, Some(name)) => cmd::run::Source::Sample(Some(name)),
+        (None, None) => cmd::run::Source::Default,
+    }
+}
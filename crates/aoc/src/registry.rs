@@ -0,0 +1,33 @@
+use aoc_core::Day;
+
+/// Every solved day, oldest first.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<aoc_2016_day_1::Taxicab>(2016, 1),
+        Day::new::<aoc_2016_day_2::Keypad>(2016, 2),
+        Day::new::<aoc_2016_day_3::Triangle>(2016, 3),
+        Day::new::<aoc_2016_day_4::Room>(2016, 4),
+        Day::new::<aoc_2016_day_5::Door>(2016, 5),
+        Day::new::<aoc_2016_day_6::Signal>(2016, 6),
+        Day::new::<aoc_2016_day_7::IP>(2016, 7),
+        Day::new::<aoc_2016_day_8::Screen>(2016, 8),
+        Day::new::<aoc_2016_day_9::Decompressor>(2016, 9),
+        Day::new::<aoc_2017_day_4::Passphrase>(2017, 4),
+        Day::new::<aoc_2017_day_5::InstructionList>(2017, 5),
+        Day::new::<aoc_2017_day_6::MemoryArea>(2017, 6),
+        Day::new::<aoc_2017_day_7::Program>(2017, 7),
+        Day::new::<aoc_2017_day_8::RegisterList>(2017, 8),
+        Day::new::<aoc_2017_day_9::Group>(2017, 9),
+        Day::new::<aoc_2017_day_10::KnotHash>(2017, 10),
+        Day::new::<aoc_2017_day_11::HexCoordinate>(2017, 11),
+        Day::new::<aoc_2017_day_12::Node>(2017, 12),
+        Day::new::<aoc_2017_day_13::FirewallLayer>(2017, 13),
+        Day::new::<aoc_2017_day_14::Grid>(2017, 14),
+        Day::new::<aoc_2017_day_15::Generator>(2017, 15),
+    ]
+}
+
+/// Look up a single day.
+pub fn find(year: u16, day: u8) -> Option<Day> {
+    days().into_iter().find(|d| d.year == year && d.day == day)
+}
@@ -0,0 +1,213 @@
+//! `aoc new`: a fresh Rust day wired into the workspace and the registry, so every new day starts
+//! out looking the same.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::DayId;
+
+/// Create the crate for `id` and register it. Returns every file written or changed.
+///
+/// Refuses to touch a day that already has a directory with anything in it (in any language, as
+/// `day-7` or `day-07`) or is already registered.
+pub fn create(root: &Path, id: DayId) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join(id.year.to_string());
+    for name in [format!("day-{}", id.day), format!("day-{:02}", id.day)] {
+        let dir = year_dir.join(name);
+        if is_non_empty_dir(&dir) {
+            return Err(format!("{} already exists, cannot overwrite", dir.display()));
+        }
+    }
+
+    if crate::registry::find(id.year, id.day).is_some() {
+        return Err(format!("{} is already registered", id));
+    }
+
+    let dir = year_dir.join(format!("day-{}", id.day));
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("crates/aoc/Cargo.toml");
+    let registry = root.join("crates/aoc/src/registry.rs");
+
+    // Work out every edit before writing anything, so a surprise leaves the tree untouched.
+    let workspace_toml = insert_sorted(
+        &read(&workspace)?,
+        &format!("    \"{}/day-{}\",", id.year, id.day),
+        parse_member,
+        id,
+        true,
+    )?;
+    let runner_toml = insert_sorted(
+        &read(&runner)?,
+        &format!("{} = {{ path = \"../../{}/day-{}\" }}", package(id), id.year, id.day),
+        parse_dependency,
+        id,
+        false,
+    )?;
+    let registry_rs = insert_sorted(
+        &read(&registry)?,
+        &format!(
+            "        Day::new::<{}::{}>({}, {}),",
+            package(id).replace('-', "_"),
+            struct_name(id),
+            id.year,
+            id.day
+        ),
+        parse_registration,
+        id,
+        false,
+    )?;
+
+    let files = vec![
+        (dir.join("Cargo.toml"), cargo_toml(id)),
+        (dir.join("src/lib.rs"), lib_rs(id)),
+        (dir.join("examples.toml"), examples_toml(id)),
+        (workspace, workspace_toml),
+        (runner, runner_toml),
+        (registry, registry_rs),
+    ];
+
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for (path, contents) in &files {
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn package(id: DayId) -> String {
+    format!("aoc-{}-day-{}", id.year, id.day)
+}
+
+fn struct_name(id: DayId) -> String {
+    format!("Day{}", id.day)
+}
+
+fn cargo_toml(id: DayId) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+authors.workspace = true
+edition = "2021"
+
+[dependencies]
+aoc-core.workspace = true
+"#,
+        package(id)
+    )
+}
+
+fn lib_rs(id: DayId) -> String {
+    format!(
+        r#"// http://adventofcode.com/{year}/day/{day}
+
+use aoc_core::{{Answer, ParseError, Solution}};
+
+pub struct {name};
+
+impl Solution for {name} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+        aoc_core::parse_lines(input, |line| Ok(line.trim().to_string()))
+    }}
+
+    fn part1(lines: &Vec<String>) -> Answer {{
+        lines.len().into()
+    }}
+
+    fn part2(_lines: &Vec<String>) -> Answer {{
+        "TODO".into()
+    }}
+}}
+"#,
+        year = id.year,
+        day = id.day,
+        name = struct_name(id)
+    )
+}
+
+fn examples_toml(id: DayId) -> String {
+    format!(
+        r#"# http://adventofcode.com/{}/day/{}
+#
+# [[example]]
+# input = """
+# ...
+# """
+# part1 = 0
+# part2 = 0
+"#,
+        id.year, id.day
+    )
+}
+
+/// Insert `new_line` among the lines that `key` recognises, keeping them in year/day order.
+/// With `group_years`, a new year gets its own blank-line-separated group, as in the workspace
+/// member list. Refuses a day that's already there.
+pub fn insert_sorted<F>(text: &str, new_line: &str, key: F, id: DayId, group_years: bool) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<(u16, u8)>,
+{
+    let mut lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, (u16, u8))> =
+        lines.iter().enumerate().filter_map(|(i, line)| key(line).map(|k| (i, k))).collect();
+
+    let new_key = (id.year, id.day);
+    if keyed.iter().any(|&(_, k)| k == new_key) {
+        return Err(format!("{} is already listed", id));
+    }
+
+    let prev = keyed.iter().rev().find(|&&(_, k)| k < new_key);
+    let next = keyed.iter().find(|&&(_, k)| k > new_key);
+
+    // Join the day's year if it has one, otherwise start a group after (or before) its neighbours.
+    match (prev, next) {
+        (Some(&(i, (year, _))), _) if year == id.year => lines.insert(i + 1, new_line),
+        (_, Some(&(i, (year, _)))) if year == id.year => lines.insert(i, new_line),
+        (Some(&(i, _)), _) => {
+            lines.insert(i + 1, new_line);
+            if group_years {
+                lines.insert(i + 1, "");
+            }
+        }
+        (None, Some(&(i, _))) => {
+            if group_years {
+                lines.insert(i, "");
+            }
+            lines.insert(i, new_line);
+        }
+        (None, None) => return Err(format!("nowhere to add {}", new_line.trim())),
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `    "2017/day-14",`
+pub fn parse_member(line: &str) -> Option<(u16, u8)> {
+    let member = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+    let (year, day) = member.split_once("/day-")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `aoc-2017-day-14 = { path = "../../2017/day-14" }`
+pub fn parse_dependency(line: &str) -> Option<(u16, u8)> {
+    let name = line.split_whitespace().next()?.strip_prefix("aoc-")?;
+    let (year, day) = name.split_once("-day-")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `        Day::new::<aoc_2017_day_14::Grid>(2017, 14),`
+pub fn parse_registration(line: &str) -> Option<(u16, u8)> {
+    let args = line.trim().strip_prefix("Day::new::<")?.rsplit_once(">(")?.1;
+    let (year, day) = args.strip_suffix("),")?.split_once(", ")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn is_non_empty_dir(path: &Path) -> bool {
+    fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_some())
+}
@@ -0,0 +1,181 @@
+//! Solving many days at once, on a pool of threads, for `aoc run --all`.
+//!
+//! Each day runs on a thread of its own, at most `jobs` at a time, so a slow day only holds up
+//! its own slot. A day that panics is reported and the rest carry on. One that runs past the
+//! timeout is reported too and its slot given to the next day; Rust can't stop a thread, so it's
+//! left running in the background until the process exits.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Day, Part};
+
+use crate::format_duration;
+
+pub struct Options {
+    /// Days to run at once.
+    pub jobs: usize,
+    /// How long each day gets, parsing and both parts together.
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Missing,
+    Error,
+    Panic,
+    Timeout,
+}
+
+/// One line of the table: an answer, or what stopped the rest of a day.
+#[derive(Clone)]
+pub struct Row {
+    pub day: Day,
+    /// `None` for a whole day that didn't finish.
+    pub part: Option<Part>,
+    pub answer: String,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+/// What a day's thread reports as it goes.
+enum Event {
+    Solved { index: usize, part: Part, answer: String, elapsed: Duration },
+    Done { index: usize },
+    Failed { index: usize, status: Status, message: String },
+}
+
+/// Solve every one of `days`, with `input` fetching each day's input just before it starts (or
+/// the row to report if it can't). Rows come back sorted by day, parts first, then whatever
+/// stopped the rest of the day.
+pub fn solve_all<F>(days: &[Day], options: &Options, mut input: F) -> Vec<Row>
+where
+    F: FnMut(&Day) -> Result<String, Row>,
+{
+    // Panics are reported in the table, not as they happen.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (tx, rx) = mpsc::channel();
+    let mut rows = vec![];
+    let mut queue = days.iter().enumerate();
+    // Days still running, by index, with their deadlines.
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+
+    loop {
+        while running.len() < options.jobs.max(1) {
+            let Some((index, &day)) = queue.next() else { break };
+
+            match input(&day) {
+                Ok(text) => {
+                    running.insert(index, Instant::now() + options.timeout);
+                    spawn(index, day, text, tx.clone());
+                }
+                Err(row) => rows.push(row),
+            }
+        }
+
+        let Some(&deadline) = running.values().min() else { break };
+
+        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            // Anything from a day that's already timed out is too late.
+            Ok(event) if !running.contains_key(&event.index()) => {}
+            Ok(Event::Solved { index, part, answer, elapsed }) => rows.push(Row {
+                day: days[index],
+                part: Some(part),
+                answer,
+                time: Some(elapsed),
+                status: Status::Ok,
+            }),
+            Ok(Event::Done { index }) => {
+                running.remove(&index);
+            }
+            Ok(Event::Failed { index, status, message }) => {
+                running.remove(&index);
+                rows.push(Row::day(days[index], status, message));
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let late: Vec<usize> = running.iter().filter(|&(_, &d)| d <= now).map(|(&i, _)| i).collect();
+
+                for index in late {
+                    running.remove(&index);
+                    rows.push(Row::day(days[index], Status::Timeout, format!("gave up after {}", format_duration(options.timeout))));
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept for spawning"),
+        }
+    }
+
+    panic::set_hook(hook);
+
+    rows.sort_by_key(|r| (r.day.year, r.day.day, r.part.is_none(), r.part));
+    rows
+}
+
+/// Parse and solve `day` on a new thread, reporting back to `tx`.
+fn spawn(index: usize, day: Day, text: String, tx: Sender<Event>) {
+    thread::spawn(move || {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            let parsed = day.parse(&text).map_err(|e| e.to_string())?;
+
+            for part in Part::BOTH {
+                let start = Instant::now();
+                let answer = day.solve_part(&parsed, part);
+                let elapsed = start.elapsed();
+                let _ = tx.send(Event::Solved { index, part, answer: answer.value().to_string(), elapsed });
+            }
+
+            Ok(())
+        }));
+
+        let _ = tx.send(match solved {
+            Ok(Ok(())) => Event::Done { index },
+            Ok(Err(message)) => Event::Failed { index, status: Status::Error, message },
+            Err(payload) => Event::Failed { index, status: Status::Panic, message: panic_message(payload) },
+        });
+    });
+}
+
+/// What a panic was called with, if it was a string.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("panicked".to_string(), |s| s.to_string()),
+    }
+}
+
+impl Event {
+    fn index(&self) -> usize {
+        match *self {
+            Event::Solved { index, .. } | Event::Done { index } | Event::Failed { index, .. } => index,
+        }
+    }
+}
+
+impl Row {
+    /// A row for a whole day that didn't finish.
+    pub fn day<T: Into<String>>(day: Day, status: Status, answer: T) -> Row {
+        Row { day, part: None, answer: answer.into(), time: None, status }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Status::Ok => "ok",
+            Status::Missing => "missing",
+            Status::Error => "error",
+            Status::Panic => "PANIC",
+            Status::Timeout => "TIMEOUT",
+        };
+
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,72 @@
+//! Reading `answers.toml` and checking answers against it, as `aoc verify` does.
+
+use std::fs;
+
+use aoc::answers::{self, Answers, Verdict};
+use aoc_core::Part;
+use tempfile::NamedTempFile;
+
+/// Write `text` to a file of its own, removed when it's dropped.
+fn fixture(text: &str) -> NamedTempFile {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), text).unwrap();
+    file
+}
+
+fn load(text: &str) -> Result<Answers, String> {
+    answers::load(fixture(text).path())
+}
+
+#[test]
+fn numbers_and_strings() {
+    let answers = load("part1 = 409147\npart2 = \"abc-def\"\n").unwrap();
+    assert_eq!(answers.get(Part::One).as_deref(), Some("409147"));
+    assert_eq!(answers.get(Part::Two).as_deref(), Some("abc-def"));
+
+    let answers = load("part1 = -12\n").unwrap();
+    assert_eq!(answers.get(Part::One).as_deref(), Some("-12"));
+    assert_eq!(answers.get(Part::Two), None);
+
+    let answers = load("part2 = \"\"\"\n#..#\n.##.\n\"\"\"\n").unwrap();
+    assert_eq!(answers.get(Part::One), None);
+    assert_eq!(answers.get(Part::Two).as_deref(), Some("#..#\n.##.\n"));
+}
+
+#[test]
+fn nothing_recorded_yet() {
+    let answers = load("# No answers yet\n").unwrap();
+    assert_eq!(answers.get(Part::One), None);
+
+    let dir = tempfile::tempdir().unwrap();
+    let answers = answers::load(&dir.path().join("answers.toml")).unwrap();
+    assert_eq!(answers.get(Part::One), None);
+    assert_eq!(answers.get(Part::Two), None);
+}
+
+#[test]
+fn rejects_what_isnt_an_answer() {
+    for text in ["part3 = 1\n", "part1 = 1.5\n", "part1 = \n"] {
+        let file = fixture(text);
+        let error = answers::load(file.path()).unwrap_err();
+
+        assert!(error.starts_with(&file.path().display().to_string()), "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn checks_each_part() {
+    let answers = load("part1 = 991\npart2 = \"\"\"\n#..#\n.##.\n\"\"\"\n").unwrap();
+
+    assert_eq!(answers.check(Part::One, "991"), Verdict::Pass);
+    assert_eq!(answers.check(Part::One, " 991\n"), Verdict::Pass);
+    assert_eq!(answers.check(Part::One, "990"), Verdict::Fail { want: "991".to_string(), diff: "- 991\n+ 990".to_string() });
+
+    assert_eq!(answers.check(Part::Two, "\n#..#\n.##.\n"), Verdict::Pass);
+    assert_eq!(
+        answers.check(Part::Two, "#..#\n.#..\n...#"),
+        Verdict::Fail { want: "#..#\n.##.\n".to_string(), diff: "  #..#\n- .##.\n+ .#..\n+ ...#".to_string() }
+    );
+
+    let answers = load("part1 = 991\n").unwrap();
+    assert_eq!(answers.check(Part::Two, "991"), Verdict::Unrecorded);
+}
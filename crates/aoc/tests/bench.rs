@@ -0,0 +1,109 @@
+//! `aoc bench`'s history file, and comparing new timings with the last ones recorded.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc::bench::{self, Measurement, Record, Stage};
+use aoc::registry;
+use aoc_core::Part;
+use tempfile::TempDir;
+
+/// A history file of its own, not there yet, in a directory removed when it's dropped.
+fn history() -> (TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bench-history.jsonl");
+    (dir, path)
+}
+
+fn record(timestamp: u64, year: u16, day: u8, stage: &str, median_ns: u64) -> Record {
+    Record { timestamp, year, day, stage: stage.to_string(), median_ns, min_ns: median_ns / 2, runs: 10 }
+}
+
+fn measurement(stage: Stage, median: Duration) -> Measurement {
+    Measurement { stage, median, min: median, max: median, runs: 10 }
+}
+
+/// Each record as (timestamp, year, day, stage, median), to compare in one go.
+fn summarise(records: &[Record]) -> Vec<(u64, u16, u8, String, u64)> {
+    records.iter().map(|r| (r.timestamp, r.year, r.day, r.stage.clone(), r.median_ns)).collect()
+}
+
+#[test]
+fn no_history_yet() {
+    let (_dir, path) = history();
+    assert!(bench::load_history(&path).unwrap().is_empty());
+}
+
+#[test]
+fn appends_and_reads_back() {
+    let (_dir, path) = history();
+    bench::append_history(&path, &[record(1, 2017, 4, "parse", 1_500), record(1, 2017, 4, "part1", 20_000)]).unwrap();
+    bench::append_history(&path, &[record(2, 2017, 4, "parse", 1_400)]).unwrap();
+
+    let text = fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        text.lines().next(),
+        Some(r#"{"timestamp":1,"year":2017,"day":4,"stage":"parse","median_ns":1500,"min_ns":750,"runs":10}"#)
+    );
+
+    let loaded = bench::load_history(&path).unwrap();
+    assert_eq!(
+        summarise(&loaded),
+        vec![
+            (1, 2017, 4, "parse".to_string(), 1_500),
+            (1, 2017, 4, "part1".to_string(), 20_000),
+            (2, 2017, 4, "parse".to_string(), 1_400),
+        ]
+    );
+}
+
+#[test]
+fn skips_blank_lines_and_points_at_bad_ones() {
+    let (_dir, path) = history();
+    let good = r#"{"timestamp":1,"year":2017,"day":4,"stage":"parse","median_ns":1500,"min_ns":750,"runs":10}"#;
+
+    fs::write(&path, format!("{}\n\n  \n{}\n", good, good)).unwrap();
+    assert_eq!(bench::load_history(&path).unwrap().len(), 2);
+
+    fs::write(&path, format!("{}\n\n{{\"timestamp\":1}}\n", good)).unwrap();
+    let error = bench::load_history(&path).unwrap_err();
+    assert!(error.starts_with(&format!("{}:3: ", path.display())), "{}", error);
+}
+
+#[test]
+fn compares_with_the_latest_run_of_the_same_stage() {
+    let day = registry::find(2017, 4).unwrap();
+    let history = [
+        record(1, 2017, 4, "part1", 1_000),
+        record(1, 2017, 5, "part1", 2_000),
+        record(2, 2017, 4, "part1", 3_000),
+        record(2, 2017, 4, "part2", 4_000),
+        record(3, 2016, 4, "part1", 5_000),
+    ];
+
+    let previous = |stage| bench::previous(&history, &day, stage).map(|r| r.median_ns);
+    assert_eq!(previous(Stage::Part(Part::One)), Some(3_000));
+    assert_eq!(previous(Stage::Part(Part::Two)), Some(4_000));
+    assert_eq!(previous(Stage::Parse), None);
+}
+
+#[test]
+fn regressions_beyond_the_threshold_and_the_noise() {
+    let before = record(1, 2017, 4, "part1", 1_000_000);
+    let part1 = |micros| measurement(Stage::Part(Part::One), Duration::from_micros(micros));
+
+    assert_eq!(part1(1_200).change(&before), 20.0);
+    assert_eq!(part1(900).change(&before), -10.0);
+
+    assert!(part1(1_200).regressed(&before, 10.0));
+    assert!(!part1(1_200).regressed(&before, 20.0));
+    assert!(!part1(1_100).regressed(&before, 20.0));
+    assert!(!part1(500).regressed(&before, 10.0));
+
+    // Doubling is still only noise at this size.
+    let before = record(1, 2017, 4, "part1", 50_000);
+    assert_eq!(part1(100).change(&before), 100.0);
+    assert!(!part1(100).regressed(&before, 10.0));
+    assert!(part1(200).regressed(&before, 10.0));
+}
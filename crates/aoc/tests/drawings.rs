@@ -0,0 +1,12 @@
+//! `aoc draw` on a day with nothing to draw.
+
+use aoc::registry;
+use aoc_core::Drawing;
+
+#[test]
+fn most_days_cant_draw() {
+    let day = registry::find(2017, 4).expect("registered");
+    let parsed = day.parse("aa bb").unwrap();
+
+    assert_eq!(day.draw(&parsed, Drawing::Text), None);
+}
@@ -0,0 +1,65 @@
+//! Every day's `examples.toml`, run through its `Solution`.
+
+use std::fs;
+
+use aoc::{examples, registry};
+
+#[test]
+fn examples_match() {
+    let root = aoc::root();
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for day in registry::days() {
+        let path = examples::path(&root, &day);
+        let examples = examples::load(&path).unwrap_or_else(|e| panic!("{}", e));
+
+        for (i, example) in examples.iter().enumerate() {
+            checked += 1;
+
+            let name = match example.name {
+                Some(ref name) => format!("{} example {} ({})", day, i + 1, name),
+                None => format!("{} example {}", day, i + 1),
+            };
+
+            for failure in example.check(&day) {
+                failures.push(format!("{}, {}", name, failure));
+            }
+        }
+    }
+
+    assert!(checked > 0, "no examples found under {}", root.display());
+    assert!(
+        failures.is_empty(),
+        "{} example answers were wrong:\n\n{}\n",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
+
+/// 2017/10's example hashes of strings that aren't lists of lengths, so its parser won't take them.
+#[test]
+fn knot_hashes_of_any_string() {
+    assert_eq!(knot_hash::hex(""), "a2582a3a0e66e6e86e3812dcb672a272");
+    assert_eq!(knot_hash::hex("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+}
+
+/// `examples.toml` in a directory of its own, with `sample.txt` beside it, loaded.
+fn load(text: &str) -> Result<Vec<examples::Example>, String> {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("sample.txt"), "R8, R4, R4, R8").unwrap();
+    fs::write(dir.path().join("examples.toml"), text).unwrap();
+    examples::load(&dir.path().join("examples.toml"))
+}
+
+#[test]
+fn input_or_file_but_not_both() {
+    let examples = load("[[example]]\ninput = \"R2\"\npart1 = 2\n\n[[example]]\nfile = \"sample.txt\"\npart2 = 4\n").unwrap();
+    assert_eq!(examples.iter().map(|e| e.input()).collect::<Vec<_>>(), ["R2", "R8, R4, R4, R8"]);
+
+    let error = load("[[example]]\ninput = \"R2\"\n\n[[example]]\ninput = \"R2\"\nfile = \"sample.txt\"\n").unwrap_err();
+    assert!(error.ends_with(": example 2 has both `input` and `file`; it needs just one"), "{}", error);
+
+    let error = load("[[example]]\npart1 = 2\n").unwrap_err();
+    assert!(error.ends_with(": example 1 needs `input` or `file`"), "{}", error);
+}
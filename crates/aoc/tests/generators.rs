@@ -0,0 +1,52 @@
+//! Every day's input generator, checked against its own `Solution`.
+
+use aoc::registry;
+use aoc_core::{Day, Part};
+
+/// Too slow to solve in a test whatever the size: they hash millions of times over.
+const SLOW: &[(u16, u8)] = &[(2016, 5), (2017, 15)];
+
+fn generate(day: &Day, seed: u64, size: usize) -> String {
+    day.generate(seed, Some(size)).unwrap_or_else(|| panic!("{} has no input generator", day))
+}
+
+#[test]
+fn generated_inputs_parse() {
+    let mut failures = vec![];
+
+    for day in registry::days() {
+        for seed in 1..=5 {
+            let input = generate(&day, seed, 12);
+            if let Err(e) = day.parse(&input) {
+                failures.push(format!("{} seed {}: {}\n{}", day, seed, e, input));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "generated inputs didn't parse:\n\n{}\n", failures.join("\n\n"));
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in registry::days() {
+        assert_eq!(generate(&day, 7, 12), generate(&day, 7, 12), "{} isn't repeatable", day);
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for day in registry::days() {
+        if SLOW.contains(&(day.year, day.day)) {
+            continue;
+        }
+
+        for seed in 1..=3 {
+            let input = generate(&day, seed, 12);
+            let parsed = day.parse(&input).unwrap_or_else(|e| panic!("{} seed {}: {}", day, seed, e));
+
+            for part in Part::BOTH {
+                day.solve_part(&parsed, part);
+            }
+        }
+    }
+}
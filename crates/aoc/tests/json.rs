@@ -0,0 +1,49 @@
+//! The exact lines `aoc run --format json` prints, escaping included.
+
+use std::time::Duration;
+
+use aoc::json::{answer_record, error_record};
+use aoc::registry;
+use aoc_core::{Answer, ParseError, Part};
+
+#[test]
+fn answers() {
+    let day = registry::find(2017, 4).unwrap();
+
+    assert_eq!(
+        answer_record(&day, Part::One, &Answer::from(451), Duration::from_micros(1_234)),
+        r#"{"year":2017,"day":4,"part":1,"answer":"451","elapsed_ns":1234000,"detail":null}"#
+    );
+    assert_eq!(
+        answer_record(&day, Part::Two, &Answer::from("abc").with_detail("3 of 512"), Duration::from_nanos(7)),
+        r#"{"year":2017,"day":4,"part":2,"answer":"abc","elapsed_ns":7,"detail":"3 of 512"}"#
+    );
+}
+
+#[test]
+fn escapes_answers() {
+    let day = registry::find(2016, 8).unwrap();
+    let answer = Answer::from("#..#\n\"q\"\t\\ \u{1}é").with_detail("line\r\nbreak");
+
+    assert_eq!(
+        answer_record(&day, Part::Two, &answer, Duration::ZERO),
+        r##"{"year":2016,"day":8,"part":2,"answer":"#..#\n\"q\"\t\\ \u0001é","elapsed_ns":0,"detail":"line\r\nbreak"}"##
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let day = registry::find(2017, 13).unwrap();
+    let error = ParseError::new(2, 4, "x\"y", "a \"range\"");
+
+    assert_eq!(
+        error_record(&day, &error),
+        r#"{"year":2017,"day":13,"error":"line 2, column 4: expected a \"range\", found \"x\"y\"","line":2,"column":4,"text":"x\"y","expected":"a \"range\""}"#
+    );
+
+    let error = ParseError::new(1, 1, "", "a number");
+    assert_eq!(
+        error_record(&day, &error),
+        r#"{"year":2017,"day":13,"error":"line 1, column 1: expected a number, found nothing","line":1,"column":1,"text":"","expected":"a number"}"#
+    );
+}
@@ -0,0 +1,42 @@
+//! `aoc run 2016/2 --keypad`'s keypad, read from a file at run time.
+
+use std::fs;
+
+use aoc::keypad;
+use aoc_2016_day_2::Keypad;
+use aoc_core::Solution;
+use tempfile::NamedTempFile;
+
+/// Write keypad art to a file of its own, removed when it's dropped.
+fn art(text: &str) -> NamedTempFile {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), text).unwrap();
+    file
+}
+
+#[test]
+fn types_on_a_keypad_from_a_file() {
+    let file = art("\n123\n456\n789\n*0#\n");
+    let mut keypad = keypad::load(file.path(), '0').unwrap();
+
+    assert_eq!(keypad.code(&Keypad::parse("U\nLDD\nRR\nUUUU").unwrap()), "8*#3");
+}
+
+#[test]
+fn names_the_file_when_it_cant() {
+    let file = art("123\n4 1\n");
+    let error = keypad::load(file.path(), '2').err().expect("repeated label");
+    assert_eq!(
+        error,
+        format!("{}: line 2, column 3: expected a label no other button has, found \"1\"", file.path().display())
+    );
+
+    let file = art("123\n456\n");
+    let error = keypad::load(file.path(), '9').err().expect("no start");
+    assert_eq!(error, format!("{}: there's no 9 button to start on", file.path().display()));
+
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("keypad.txt");
+    let error = keypad::load(&missing, '5').err().expect("no file");
+    assert!(error.starts_with(&format!("Couldn't read {}: ", missing.display())), "{}", error);
+}
@@ -0,0 +1,186 @@
+//! Parsers, run on generated input. Types that print themselves in the puzzle's input syntax are
+//! checked to parse back into the same value: each value is made by parsing text built from
+//! random fields, then printed and parsed again. Every day's parser is also fed junk, which it
+//! has to reject without panicking (`fuzz/` does the same for longer).
+
+use std::fmt::{Debug, Display};
+
+use aoc::registry;
+use aoc_core::{ParseError, Part};
+use proptest::prelude::*;
+
+/// Parse `text`, then check that printing the value and parsing that gives the value back.
+fn round_trip<T, F>(text: &str, parse: F) -> Result<(), TestCaseError>
+where
+    T: Display + Debug + PartialEq,
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    let value = parse(text).map_err(|e| TestCaseError::fail(format!("{:?} didn't parse: {}", text, e)))?;
+    let shown = value.to_string();
+    let again = parse(&shown).map_err(|e| TestCaseError::fail(format!("{:?} didn't parse: {}", shown, e)))?;
+
+    prop_assert_eq!(again, value, "{:?} printed as {:?}", text, shown);
+    Ok(())
+}
+
+fn name() -> impl Strategy<Value = String> {
+    "[a-z]{1,8}"
+}
+
+/// Any key will do for this one, and parsing it hashes it 128 times over.
+const SLOW: &[(u16, u8)] = &[(2017, 14)];
+
+/// Parsed fine, but solving hashes or generates millions of times over whatever the input.
+const SLOW_TO_SOLVE: &[(u16, u8)] = &[(2016, 5), (2017, 15)];
+
+/// Anything at all, or something made of the characters puzzle inputs use.
+fn junk() -> impl Strategy<Value = String> {
+    prop_oneof![any::<String>(), "[a-zLRUD0-9 ,()<>{}!=:x>\n-]{0,100}"]
+}
+
+proptest! {
+    #[test]
+    fn parsers_never_panic(text in junk()) {
+        for day in registry::days() {
+            if SLOW.contains(&(day.year, day.day)) {
+                continue;
+            }
+
+            // Whatever parses has to solve without panicking too.
+            if let Ok(parsed) = day.parse(&text) {
+                if !SLOW_TO_SOLVE.contains(&(day.year, day.day)) {
+                    for part in Part::BOTH {
+                        day.solve_part(&parsed, part);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn taxicab_instruction(turn in "[LR]", steps: u32) {
+        round_trip(&format!("{}{}", turn, steps), aoc_2016_day_1::Instruction::parse)?;
+    }
+
+    #[test]
+    fn screen_operation(kind in 0..3, a: u32, b: u32) {
+        let text = match kind {
+            0 => format!("rect {}x{}", a, b),
+            1 => format!("rotate row y={} by {}", a, b),
+            _ => format!("rotate column x={} by {}", a, b),
+        };
+        round_trip(&text, aoc_2016_day_8::Operation::parse)?;
+    }
+
+    #[test]
+    fn decompressor_marker(num_chars: usize, repeats: u32) {
+        round_trip(&format!("({}x{})", num_chars, repeats), aoc_2016_day_9::Marker::parse)?;
+    }
+
+    #[test]
+    fn program_reference(name in name(), weight: u32, held in prop::collection::vec(name(), 0..6)) {
+        let text = if held.is_empty() {
+            format!("{} ({})", name, weight)
+        } else {
+            format!("{} ({}) -> {}", name, weight, held.join(", "))
+        };
+        round_trip(&text, aoc_2017_day_7::ProgramReference::parse)?;
+    }
+
+    #[test]
+    fn register_instruction(
+        register in name(),
+        operation in "inc|dec",
+        amount: i32,
+        tested in name(),
+        comparison in "==|!=|>|>=|<|<=",
+        value: i32,
+    ) {
+        let text = format!("{} {} {} if {} {} {}", register, operation, amount, tested, comparison, value);
+        round_trip(&text, aoc_2017_day_8::Instruction::parse)?;
+    }
+}
+
+/// Input that parsed once and then panicked, overflowed or hung while solving, and what it solves
+/// to now.
+#[test]
+fn solves_what_once_went_wrong() {
+    let cases: &[(u16, u8, &str, [&str; 2])] = &[
+        (2016, 1, "R2147483647, L1, R1", ["2147483649", "none"]),
+        (2016, 3, "2000000000 2000000000 2000000000", ["1", "0"]),
+        (2016, 4, "a-2000000000[a]\na-2000000000[a]", ["4000000000", "none"]),
+        (2016, 9, "(1x2)é", ["2", "2"]),
+        (2016, 9, "(15x4294967295)(1x4294967295)a", ["64424509425", "18446744065119617025"]),
+        (2017, 5, "2147483647\n0", ["1", "1"]),
+        (2017, 8, "a inc 2147483647 if b == 0\na inc 1 if b == 0", ["2147483648", "2147483648"]),
+        (2017, 8, "a dec -2147483648 if b == 0", ["2147483648", "2147483648"]),
+        (2017, 13, "4294967295: 2", ["0", "0"]),
+    ];
+
+    for &(year, day, input, answers) in cases {
+        let day = registry::find(year, day).expect("registered");
+        let Ok(parsed) = day.parse(input) else { panic!("{} rejected {:?}", day, input) };
+
+        for (part, answer) in Part::BOTH.into_iter().zip(answers) {
+            assert_eq!(day.solve_part(&parsed, part).value(), answer, "{} on {:?}", day, input);
+        }
+    }
+}
+
+/// Input each day turns away, and where each error points.
+#[test]
+fn rejected_where_it_goes_wrong() {
+    let cases: &[(u16, u8, &str, (usize, usize))] = &[
+        (2016, 1, "R2, L-5", (1, 6)),
+        (2016, 1, "R-2147483648", (1, 2)),
+        (2016, 1, "R+5", (1, 2)),
+        (2016, 1, "R4294967296", (1, 2)),
+        (2016, 4, "abc-cDe-123[abcde]", (1, 6)),
+        (2016, 4, "abc-123[ab1de]", (1, 11)),
+        (2017, 6, "", (1, 1)),
+        (2017, 6, " \n\t\n", (1, 1)),
+        (2017, 7, "\nab (1) -> cd\n  cd (2)\ncd (3)", (4, 1)),
+        (2017, 7, "ab (1) -> cd\ncd (2) -> ab", (2, 11)),
+        (2017, 7, "ab (1) -> cd, ef\ncd (2)\nef (3)\ngh (4)", (4, 1)),
+        (2017, 7, "ab (1) -> cd, ef\ncd (2) -> ef\nef (3)", (2, 11)),
+        (2017, 7, "ab (1) -> cd, xy\ncd (2)", (1, 15)),
+        (2017, 8, "\n  \n", (1, 1)),
+        (2017, 10, "3,4,abc", (1, 5)),
+        (2017, 10, "3, 300,1", (1, 4)),
+        (2017, 11, "n,ne,^", (1, 6)),
+        (2017, 11, "n,NE", (1, 3)),
+        (2017, 12, "", (1, 1)),
+        (2017, 12, "0 <-> 0\n1 <-> 5", (2, 7)),
+        (2017, 13, "2: 3\n1: 2", (2, 1)),
+        (2017, 13, "0: 2147483648", (1, 4)),
+        (2017, 15, "Generator A starts with 1\nGenerator B starts with 2147483647", (2, 25)),
+    ];
+
+    for &(year, day, input, position) in cases {
+        let day = registry::find(year, day).expect("registered");
+        match day.parse(input) {
+            Ok(_) => panic!("{} accepted {:?}", day, input),
+            Err(e) => assert_eq!((e.line, e.column), position, "{} on {:?}: {}", day, input, e),
+        }
+    }
+}
+
+#[test]
+fn too_deep_to_recurse_through() {
+    let groups = |depth| format!("{}{}", "{".repeat(depth), "}".repeat(depth));
+    let tower = |height: usize| {
+        let mut lines: Vec<String> = (1..height).map(|i| format!("p{} (1) -> p{}", i, i + 1)).collect();
+        lines.push(format!("p{} (1)", height));
+        lines.join("\n")
+    };
+
+    let day = registry::find(2017, 9).expect("registered");
+    assert!(day.parse(&groups(1000)).is_ok());
+    let e = day.parse(&groups(100_000)).err().expect("too deep");
+    assert_eq!((e.line, e.column), (1, 1001), "{}", e);
+
+    let day = registry::find(2017, 7).expect("registered");
+    assert!(day.parse(&tower(1000)).is_ok());
+    let e = day.parse(&tower(100_000)).err().expect("too tall");
+    assert_eq!((e.line, e.column), (1000, 14), "{}", e);
+}
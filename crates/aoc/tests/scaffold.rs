@@ -0,0 +1,113 @@
+//! `aoc new`'s edits to the workspace, the runner's dependencies and the registry, made on
+//! fixture copies of each.
+
+use aoc::scaffold::{self, insert_sorted, parse_dependency, parse_member, parse_registration};
+use aoc::DayId;
+
+const WORKSPACE: &str = r#"[workspace]
+members = [
+    "crates/aoc",
+    "crates/aoc-core",
+
+    "2016/day-1",
+    "2016/day-2",
+
+    "2017/day-4",
+    "2017/day-6",
+]
+exclude = ["fuzz"]
+"#;
+
+const DEPENDENCIES: &str = r#"[dependencies]
+aoc-core.workspace = true
+aoc-2016-day-1 = { path = "../../2016/day-1" }
+aoc-2017-day-4 = { path = "../../2017/day-4" }
+aoc-2017-day-6 = { path = "../../2017/day-6" }
+"#;
+
+const REGISTRY: &str = r#"pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<aoc_2016_day_1::Taxicab>(2016, 1),
+        Day::new::<aoc_2017_day_4::Passphrase>(2017, 4),
+        Day::new::<aoc_2017_day_6::Memory>(2017, 6),
+    ]
+}
+"#;
+
+fn id(year: u16, day: u8) -> DayId {
+    DayId { year, day }
+}
+
+#[test]
+fn recognises_each_kind_of_line() {
+    assert_eq!(parse_member(r#"    "2017/day-14","#), Some((2017, 14)));
+    assert_eq!(parse_member(r#"    "crates/aoc","#), None);
+    assert_eq!(parse_member("members = ["), None);
+
+    assert_eq!(parse_dependency(r#"aoc-2017-day-14 = { path = "../../2017/day-14" }"#), Some((2017, 14)));
+    assert_eq!(parse_dependency("aoc-core.workspace = true"), None);
+    assert_eq!(parse_dependency("[dependencies]"), None);
+
+    assert_eq!(parse_registration("        Day::new::<aoc_2017_day_14::Grid>(2017, 14),"), Some((2017, 14)));
+    assert_eq!(parse_registration("    vec!["), None);
+    assert_eq!(parse_registration("        Day::new::<aoc_2017_day_14::Grid>(2017, 14)"), None);
+}
+
+#[test]
+fn inserts_in_the_middle() {
+    let new_line = r#"    "2017/day-5","#;
+    let edited = insert_sorted(WORKSPACE, new_line, parse_member, id(2017, 5), true).unwrap();
+    assert_eq!(edited, WORKSPACE.replace("    \"2017/day-6\",", "    \"2017/day-5\",\n    \"2017/day-6\","));
+
+    let new_line = r#"aoc-2017-day-5 = { path = "../../2017/day-5" }"#;
+    let edited = insert_sorted(DEPENDENCIES, new_line, parse_dependency, id(2017, 5), false).unwrap();
+    assert_eq!(edited, DEPENDENCIES.replace("aoc-2017-day-6", &format!("{}\naoc-2017-day-6", new_line)));
+}
+
+#[test]
+fn inserts_at_the_end() {
+    let new_line = "        Day::new::<aoc_2017_day_7::Tower>(2017, 7),";
+    let edited = insert_sorted(REGISTRY, new_line, parse_registration, id(2017, 7), false).unwrap();
+
+    let lines: Vec<&str> = edited.lines().collect();
+    assert_eq!(lines[4], "        Day::new::<aoc_2017_day_6::Memory>(2017, 6),");
+    assert_eq!(lines[5], new_line);
+    assert_eq!(lines[6], "    ]");
+}
+
+#[test]
+fn starts_a_new_year_group() {
+    let new_line = r#"    "2018/day-1","#;
+    let edited = insert_sorted(WORKSPACE, new_line, parse_member, id(2018, 1), true).unwrap();
+    assert_eq!(edited, WORKSPACE.replace("    \"2017/day-6\",", "    \"2017/day-6\",\n\n    \"2018/day-1\","));
+
+    // Only the workspace groups its years.
+    let new_line = r#"aoc-2018-day-1 = { path = "../../2018/day-1" }"#;
+    let edited = insert_sorted(DEPENDENCIES, new_line, parse_dependency, id(2018, 1), false).unwrap();
+    assert_eq!(edited, format!("{}{}\n", DEPENDENCIES, new_line));
+
+    // A year before any other goes in front, in a group of its own.
+    let new_line = r#"    "2015/day-3","#;
+    let edited = insert_sorted(WORKSPACE, new_line, parse_member, id(2015, 3), true).unwrap();
+    assert_eq!(edited, WORKSPACE.replace("    \"2016/day-1\",", "    \"2015/day-3\",\n\n    \"2016/day-1\","));
+}
+
+#[test]
+fn refuses_a_day_thats_already_there() {
+    let new_line = r#"    "2017/day-4","#;
+    assert!(insert_sorted(WORKSPACE, new_line, parse_member, id(2017, 4), true).is_err());
+
+    let new_line = "        Day::new::<aoc_2016_day_1::Taxicab>(2016, 1),";
+    assert!(insert_sorted(REGISTRY, new_line, parse_registration, id(2016, 1), false).is_err());
+}
+
+#[test]
+fn refuses_a_registered_day_before_writing_anything() {
+    // Nothing is written, so the root doesn't need to exist.
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("missing");
+    let error = scaffold::create(&root, id(2017, 4)).unwrap_err();
+
+    assert_eq!(error, "2017/4 is already registered");
+    assert!(!root.exists());
+}
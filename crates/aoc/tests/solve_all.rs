@@ -0,0 +1,149 @@
+//! `aoc run --all`'s thread pool, on stub days that answer, hang, panic or count how many of them
+//! are running at once.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use aoc::solve_all::{solve_all, Options, Row, Status};
+use aoc_core::{Answer, Day, ParseError, Part, Solution};
+
+/// Doubles a number.
+struct Quick;
+
+impl Solution for Quick {
+    type Input = u32;
+
+    fn parse(input: &str) -> Result<u32, ParseError> {
+        let input = input.trim();
+        input.parse().map_err(|_| ParseError::within(input, input, "a number"))
+    }
+
+    fn part1(n: &u32) -> Answer {
+        (*n).into()
+    }
+
+    fn part2(n: &u32) -> Answer {
+        (n * 2).into()
+    }
+}
+
+/// Never finishes part 1.
+struct Hangs;
+
+impl Solution for Hangs {
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &()) -> Answer {
+        loop {
+            thread::sleep(Duration::from_secs(3600));
+        }
+    }
+
+    fn part2(_: &()) -> Answer {
+        unreachable!()
+    }
+}
+
+/// Panics in part 2, having answered part 1.
+struct Panics;
+
+impl Solution for Panics {
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &()) -> Answer {
+        1.into()
+    }
+
+    fn part2(_: &()) -> Answer {
+        panic!("stub panicked")
+    }
+}
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static MOST_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+/// Takes a while over part 1, keeping count of how many are doing the same.
+struct Sleepy;
+
+impl Solution for Sleepy {
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &()) -> Answer {
+        let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+        MOST_RUNNING.fetch_max(running, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(50));
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+
+        0.into()
+    }
+
+    fn part2(_: &()) -> Answer {
+        0.into()
+    }
+}
+
+/// Each row as (day, part, answer, status), to compare in one go.
+fn summarise(rows: &[Row]) -> Vec<(u8, Option<Part>, String, Status)> {
+    rows.iter().map(|r| (r.day.day, r.part, r.answer.clone(), r.status)).collect()
+}
+
+#[test]
+fn hangs_and_panics_dont_stop_the_rest() {
+    let days = [
+        Day::new::<Quick>(1, 1),
+        Day::new::<Hangs>(1, 2),
+        Day::new::<Panics>(1, 3),
+        Day::new::<Quick>(1, 4),
+        Day::new::<Quick>(1, 5),
+        Day::new::<Quick>(1, 6),
+    ];
+    let options = Options { jobs: 2, timeout: Duration::from_millis(300) };
+
+    let rows = solve_all(&days, &options, |day| match day.day {
+        5 => Err(Row::day(*day, Status::Missing, "no input")),
+        6 => Ok("six".to_string()),
+        n => Ok(format!("{}", n * 10)),
+    });
+
+    let one = Some(Part::One);
+    let two = Some(Part::Two);
+    assert_eq!(
+        summarise(&rows),
+        vec![
+            (1, one, "10".to_string(), Status::Ok),
+            (1, two, "20".to_string(), Status::Ok),
+            (2, None, "gave up after 300.0ms".to_string(), Status::Timeout),
+            (3, one, "1".to_string(), Status::Ok),
+            (3, None, "stub panicked".to_string(), Status::Panic),
+            (4, one, "40".to_string(), Status::Ok),
+            (4, two, "80".to_string(), Status::Ok),
+            (5, None, "no input".to_string(), Status::Missing),
+            (6, None, "line 1, column 1: expected a number, found \"six\"".to_string(), Status::Error),
+        ]
+    );
+}
+
+#[test]
+fn no_more_than_jobs_at_once() {
+    let days: Vec<Day> = (1..=9).map(|n| Day::new::<Sleepy>(2, n)).collect();
+    let options = Options { jobs: 3, timeout: Duration::from_secs(60) };
+
+    let rows = solve_all(&days, &options, |_| Ok(String::new()));
+
+    assert_eq!(rows.len(), 18);
+    assert!(rows.iter().all(|r| r.status == Status::Ok));
+    assert_eq!(MOST_RUNNING.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,129 @@
+//! Union-find over the elements `0..len`: merging groups and asking which group something is in,
+//! both in near-constant time, for puzzles about connected pipes, regions and the like.
+//!
+//! Path compression and union by rank keep every tree shallow, and both are iterative, so a few
+//! million elements in one long chain is fine.
+
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> DisjointSet {
+        DisjointSet { parent: (0..len).collect(), rank: vec![0; len], size: vec![1; len], count: len }
+    }
+
+    /// Add another element in a set of its own, returning it.
+    pub fn push(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.rank.push(0);
+        self.size.push(1);
+        self.count += 1;
+        element
+    }
+
+    /// How many elements there are.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// How many separate sets there are.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The representative of `element`'s set, the same for every element in it until the next
+    /// [`union`](DisjointSet::union).
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut element = element;
+        while self.parent[element] != root {
+            let next = self.parent[element];
+            self.parent[element] = root;
+            element = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets holding `a` and `b`. Returns false if they were already one set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (parent, child) = if self.rank[a] >= self.rank[b] { (a, b) } else { (b, a) };
+        if self.rank[parent] == self.rank[child] {
+            self.rank[parent] += 1;
+        }
+
+        self.parent[child] = parent;
+        self.size[parent] += self.size[child];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements are in `element`'s set, itself included.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Every element in `element`'s set, in order.
+    pub fn members(&mut self, element: usize) -> Vec<usize> {
+        let root = self.find(element);
+        (0..self.len()).filter(|&e| self.find(e) == root).collect()
+    }
+
+    /// Every set, each in order, ordered by their smallest element.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut sets: Vec<Vec<usize>> = vec![];
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if index[root] == usize::MAX {
+                index[root] = sets.len();
+                sets.push(vec![]);
+            }
+            sets[index[root]].push(element);
+        }
+
+        sets
+    }
+
+    /// The size of every set, ordered by their smallest element.
+    pub fn sizes(&mut self) -> Vec<usize> {
+        let mut seen = vec![false; self.len()];
+        let mut sizes = vec![];
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if !seen[root] {
+                seen[root] = true;
+                sizes.push(self.size[root]);
+            }
+        }
+
+        sizes
+    }
+}
@@ -0,0 +1,55 @@
+use disjoint_set::DisjointSet;
+
+#[test]
+fn union_and_find() {
+    let mut set = DisjointSet::new(6);
+    assert_eq!(set.count(), 6);
+
+    assert!(set.union(0, 1));
+    assert!(set.union(4, 5));
+    assert!(set.union(1, 4));
+    assert!(!set.union(5, 0));
+
+    assert!(set.connected(0, 5));
+    assert!(!set.connected(0, 2));
+    assert_eq!(set.count(), 3);
+    assert_eq!(set.size_of(5), 4);
+    assert_eq!(set.size_of(3), 1);
+}
+
+#[test]
+fn members_sets_and_sizes() {
+    let mut set = DisjointSet::new(5);
+    set.union(3, 1);
+    set.union(4, 2);
+    set.union(2, 3);
+
+    assert_eq!(set.members(4), vec![1, 2, 3, 4]);
+    assert_eq!(set.sets(), vec![vec![0], vec![1, 2, 3, 4]]);
+    assert_eq!(set.sizes(), vec![1, 4]);
+}
+
+#[test]
+fn push() {
+    let mut set = DisjointSet::new(1);
+    let element = set.push();
+
+    assert_eq!(element, 1);
+    assert_eq!(set.count(), 2);
+
+    set.union(0, element);
+    assert_eq!(set.count(), 1);
+}
+
+#[test]
+fn long_chain() {
+    let len = 2_000_000;
+    let mut set = DisjointSet::new(len);
+
+    for i in 1..len {
+        set.union(i - 1, i);
+    }
+
+    assert_eq!(set.count(), 1);
+    assert_eq!(set.size_of(len - 1), len);
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use crate::point::Point2;
+
+/// Which way to face next, relative to the way you're facing now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+    Straight,
+}
+
+/// North, east, south or west.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cardinal {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// The eight points of the compass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ordinal {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Turn {
+    /// `L` or `R`.
+    pub fn parse(c: char) -> Option<Turn> {
+        match c {
+            'L' => Some(Turn::Left),
+            'R' => Some(Turn::Right),
+            _ => None,
+        }
+    }
+
+    /// Quarter turns clockwise.
+    fn quarters(self) -> usize {
+        match self {
+            Turn::Straight => 0,
+            Turn::Right => 1,
+            Turn::Around => 2,
+            Turn::Left => 3,
+        }
+    }
+}
+
+impl Cardinal {
+    /// Clockwise from north.
+    pub const ALL: [Cardinal; 4] = [Cardinal::North, Cardinal::East, Cardinal::South, Cardinal::West];
+
+    /// A compass letter (`N`, `E`, `S`, `W`), a keypad move (`U`, `R`, `D`, `L`) or an arrow
+    /// (`^`, `>`, `v`, `<`).
+    pub fn parse(c: char) -> Option<Cardinal> {
+        match c {
+            'N' | 'U' | '^' => Some(Cardinal::North),
+            'E' | 'R' | '>' => Some(Cardinal::East),
+            'S' | 'D' | 'v' => Some(Cardinal::South),
+            'W' | 'L' | '<' => Some(Cardinal::West),
+            _ => None,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Cardinal {
+        Cardinal::ALL[(self as usize + turn.quarters()) % 4]
+    }
+
+    pub fn turn_left(self) -> Cardinal {
+        self.turn(Turn::Left)
+    }
+
+    pub fn turn_right(self) -> Cardinal {
+        self.turn(Turn::Right)
+    }
+
+    pub fn turn_around(self) -> Cardinal {
+        self.turn(Turn::Around)
+    }
+
+    /// One step this way, with north as `+y`.
+    pub fn vector<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Cardinal::North => (0, 1),
+            Cardinal::East => (1, 0),
+            Cardinal::South => (0, -1),
+            Cardinal::West => (-1, 0),
+        };
+
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    /// One step this way on a grid drawn top to bottom, with north (up) as `-y`.
+    pub fn screen_vector<T: From<i8>>(self) -> Point2<T> {
+        let v = self.vector::<i8>();
+        Point2::new(T::from(v.x), T::from(-v.y))
+    }
+}
+
+impl Ordinal {
+    /// Clockwise from north.
+    pub const ALL: [Ordinal; 8] = [
+        Ordinal::North,
+        Ordinal::NorthEast,
+        Ordinal::East,
+        Ordinal::SouthEast,
+        Ordinal::South,
+        Ordinal::SouthWest,
+        Ordinal::West,
+        Ordinal::NorthWest,
+    ];
+
+    /// `n`, `ne`, `e`, ... in either case.
+    pub fn parse(s: &str) -> Option<Ordinal> {
+        Ordinal::ALL.iter().find(|d| d.to_string().eq_ignore_ascii_case(s)).copied()
+    }
+
+    /// Whole turns, so `Left` from north is west. See [`Ordinal::turn_left`] for an eighth.
+    pub fn turn(self, turn: Turn) -> Ordinal {
+        Ordinal::ALL[(self as usize + turn.quarters() * 2) % 8]
+    }
+
+    /// One point of the compass anticlockwise: north to north-west.
+    pub fn turn_left(self) -> Ordinal {
+        Ordinal::ALL[(self as usize + 7) % 8]
+    }
+
+    /// One point of the compass clockwise: north to north-east.
+    pub fn turn_right(self) -> Ordinal {
+        Ordinal::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_around(self) -> Ordinal {
+        self.turn(Turn::Around)
+    }
+
+    /// One step this way, diagonals included, with north as `+y`.
+    pub fn vector<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Ordinal::North => (0, 1),
+            Ordinal::NorthEast => (1, 1),
+            Ordinal::East => (1, 0),
+            Ordinal::SouthEast => (1, -1),
+            Ordinal::South => (0, -1),
+            Ordinal::SouthWest => (-1, -1),
+            Ordinal::West => (-1, 0),
+            Ordinal::NorthWest => (-1, 1),
+        };
+
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    /// One step this way on a grid drawn top to bottom, with north (up) as `-y`.
+    pub fn screen_vector<T: From<i8>>(self) -> Point2<T> {
+        let v = self.vector::<i8>();
+        Point2::new(T::from(v.x), T::from(-v.y))
+    }
+}
+
+impl From<Cardinal> for Ordinal {
+    fn from(d: Cardinal) -> Ordinal {
+        Ordinal::ALL[d as usize * 2]
+    }
+}
+
+/// `L`, `R`, `A` (around) or `S` (straight on).
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Turn::Left => "L",
+            Turn::Right => "R",
+            Turn::Around => "A",
+            Turn::Straight => "S",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// `N`, `E`, `S` or `W`.
+impl fmt::Display for Cardinal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Ordinal::from(*self))
+    }
+}
+
+/// `N`, `NE`, `E`, ...
+impl fmt::Display for Ordinal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Ordinal::North => "N",
+            Ordinal::NorthEast => "NE",
+            Ordinal::East => "E",
+            Ordinal::SouthEast => "SE",
+            Ordinal::South => "S",
+            Ordinal::SouthWest => "SW",
+            Ordinal::West => "W",
+            Ordinal::NorthWest => "NW",
+        };
+
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,10 @@
+//! Points, distances and compass directions, shared by the days that walk around a plane.
+//!
+//! Directions point the mathematical way, with north as `+y`; [`Cardinal::screen_vector`] and
+//! [`Ordinal::screen_vector`] flip that for grids drawn top to bottom.
+
+mod direction;
+mod point;
+
+pub use direction::{Cardinal, Ordinal, Turn};
+pub use point::{Point2, Point3, Scalar};
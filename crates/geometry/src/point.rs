@@ -0,0 +1,188 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The numbers a point can be made of.
+pub trait Scalar:
+    Copy + PartialOrd + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    fn to_f64(self) -> f64;
+
+    /// `|a - b|`, without going below zero for unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+}
+
+macro_rules! scalar {
+    ($($t:ty)*) => {
+        $(impl Scalar for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+scalar!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize f32 f64);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Scalar> Point2<T> {
+    pub fn origin() -> Self {
+        Self::default()
+    }
+
+    /// Blocks walked along the axes, the taxicab distance.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Moves when diagonal steps count as one, like a king on a chessboard.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// As the crow flies.
+    pub fn euclidean(self, other: Self) -> f64 {
+        let (dx, dy) = (self.x.abs_diff(other.x).to_f64(), self.y.abs_diff(other.y).to_f64());
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    pub fn origin() -> Self {
+        Self::default()
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)).max(self.z.abs_diff(other.z))
+    }
+
+    pub fn euclidean(self, other: Self) -> f64 {
+        let dx = self.x.abs_diff(other.x).to_f64();
+        let dy = self.y.abs_diff(other.y).to_f64();
+        let dz = self.z.abs_diff(other.z).to_f64();
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+/// Componentwise `+`, `-`, negation and scaling for both point types.
+macro_rules! vector_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $point { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $point { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $point { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+
+vector_ops!(Point2 { x, y });
+vector_ops!(Point3 { x, y, z });
+
+/// `(1, 2)`
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// `(1, 2, 3)`
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,62 @@
+use geometry::{Cardinal, Ordinal, Point2, Point3, Turn};
+
+#[test]
+fn distances() {
+    let a = Point2::new(1, -2);
+    let b = Point2::new(-3, 1);
+
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.euclidean(b), 5.0);
+    assert_eq!(Point2::new(2u32, 7).manhattan(Point2::new(5, 3)), 7);
+    assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::origin()), 6);
+    assert_eq!(Point3::new(-1, -2, 3).chebyshev(Point3::origin()), 3);
+}
+
+#[test]
+fn vector_ops() {
+    let mut p = Point2::new(1, 2) + Point2::new(3, 4) * 2;
+    assert_eq!(p, Point2::new(7, 10));
+
+    p -= Point2::new(7, 0);
+    assert_eq!(-p, Point2::new(0, -10));
+    assert_eq!(Point3::new(1, 2, 3) - Point3::new(1, 1, 1), Point3::new(0, 1, 2));
+    assert_eq!(p.to_string(), "(0, 10)");
+}
+
+#[test]
+fn turning() {
+    assert_eq!(Cardinal::North.turn(Turn::Left), Cardinal::West);
+    assert_eq!(Cardinal::West.turn_right(), Cardinal::North);
+    assert_eq!(Cardinal::East.turn_around(), Cardinal::West);
+    assert_eq!(Cardinal::South.turn(Turn::Straight), Cardinal::South);
+
+    assert_eq!(Ordinal::North.turn_left(), Ordinal::NorthWest);
+    assert_eq!(Ordinal::NorthWest.turn_right(), Ordinal::North);
+    assert_eq!(Ordinal::NorthEast.turn(Turn::Right), Ordinal::SouthEast);
+    assert_eq!(Ordinal::SouthWest.turn_around(), Ordinal::NorthEast);
+}
+
+#[test]
+fn parsing() {
+    for (chars, direction) in ["NU^", "ER>", "SDv", "WL<"].iter().zip(Cardinal::ALL) {
+        for c in chars.chars() {
+            assert_eq!(Cardinal::parse(c), Some(direction));
+        }
+    }
+    assert_eq!(Cardinal::parse('x'), None);
+
+    assert_eq!(Ordinal::parse("ne"), Some(Ordinal::NorthEast));
+    assert_eq!(Ordinal::parse("SW"), Some(Ordinal::SouthWest));
+    assert_eq!(Ordinal::parse("^"), None);
+    assert_eq!(Ordinal::parse("U"), None);
+    assert_eq!(Ordinal::parse("nne"), None);
+    assert_eq!(Turn::parse('L'), Some(Turn::Left));
+}
+
+#[test]
+fn vectors() {
+    assert_eq!(Cardinal::North.vector::<i32>(), Point2::new(0, 1));
+    assert_eq!(Cardinal::North.screen_vector::<i64>(), Point2::new(0, -1));
+    assert_eq!(Ordinal::SouthWest.vector::<f64>(), Point2::new(-1.0, -1.0));
+}
@@ -0,0 +1,216 @@
+//! A fixed-size 2D grid of cells, any of which may be empty, stored densely ([`Grid`]) or
+//! sparsely ([`SparseGrid`]).
+//!
+//! Positions are `(x, y)` with `(0, 0)` at the top left, `x` growing right and `y` down, the way
+//! puzzle text draws them.
+
+mod storage;
+
+use std::marker::PhantomData;
+
+pub use storage::{Dense, Sparse, Storage};
+
+/// `(x, y)`, from the top left.
+pub type Pos = (usize, usize);
+
+/// Up, right, down, left.
+const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Clockwise from up.
+const ALL_AROUND: [(i64, i64); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T, S = Dense<T>> {
+    width: usize,
+    height: usize,
+    cells: S,
+    marker: PhantomData<T>,
+}
+
+/// A grid that only stores its occupied cells.
+pub type SparseGrid<T> = Grid<T, Sparse<T>>;
+
+impl<T, S: Storage<T>> Grid<T, S> {
+    /// A grid with every cell empty.
+    pub fn new(width: usize, height: usize) -> Self {
+        Grid { width, height, cells: S::with_len(width * height), marker: PhantomData }
+    }
+
+    /// A grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = Self::new(width, height);
+        for pos in grid.positions().collect::<Vec<_>>() {
+            grid.set(pos, value.clone());
+        }
+        grid
+    }
+
+    /// Read ASCII art, one row per line. `cell` decides what each character holds; `None` leaves
+    /// the cell empty, as do the missing ends of short lines. Blank lines before and after the art
+    /// are ignored, but leading spaces on a line are part of it.
+    pub fn parse<F>(art: &str, cell: F) -> Self
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut lines: Vec<&str> = art.lines().collect();
+        while lines.first().is_some_and(|l| l.trim().is_empty()) {
+            lines.remove(0);
+        }
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut grid = Self::new(width, lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = cell(c) {
+                    grid.set((x, y), value);
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Draw the grid as ASCII art, one line per row, with `cell` picking each character.
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let rows: Vec<String> = (0..self.height).map(|y| self.row(y).map(&cell).collect()).collect();
+        rows.join("\n")
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at `pos`, if it's on the grid and not empty.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).and_then(|i| self.cells.get(i))
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).and_then(|i| self.cells.get_mut(i))
+    }
+
+    /// The cell at `(x, y)` with both wrapped around onto the grid, so `(-1, 0)` is the end of
+    /// the first row.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> Option<&T> {
+        self.wrap(x, y).and_then(|pos| self.get(pos))
+    }
+
+    /// `(x, y)` wrapped around onto the grid, or `None` if the grid has no cells to wrap onto.
+    pub fn wrap(&self, x: i64, y: i64) -> Option<Pos> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        Some((x.rem_euclid(self.width as i64) as usize, y.rem_euclid(self.height as i64) as usize))
+    }
+
+    /// Fill the cell at `pos`, returning what was there. Panics if `pos` is off the grid.
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        let i = self.index(pos).unwrap_or_else(|| panic!("{:?} is off a {}x{} grid", pos, self.width, self.height));
+        self.cells.replace(i, Some(value))
+    }
+
+    /// Empty the cell at `pos`, returning what was there.
+    pub fn clear(&mut self, pos: Pos) -> Option<T> {
+        self.index(pos).and_then(|i| self.cells.replace(i, None))
+    }
+
+    /// `pos` moved by `(dx, dy)`, if that's still on the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Option<Pos> {
+        let x = usize::try_from(x as i64 + dx).ok()?;
+        let y = usize::try_from(y as i64 + dy).ok()?;
+        Some((x, y)).filter(|&pos| self.contains(pos))
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = Option<&T>> + '_ {
+        (0..self.width).map(move |x| self.get((x, y)))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn col(&self, x: usize) -> impl Iterator<Item = Option<&T>> + '_ {
+        (0..self.height).map(move |y| self.get((x, y)))
+    }
+
+    /// Shift row `y` right by `by`, wrapping cells off the end back round to the start.
+    pub fn rotate_row(&mut self, y: usize, by: usize) {
+        let positions: Vec<Pos> = (0..self.width).map(|x| (x, y)).collect();
+        self.rotate(&positions, by);
+    }
+
+    /// Shift column `x` down by `by`, wrapping cells off the bottom back round to the top.
+    pub fn rotate_col(&mut self, x: usize, by: usize) {
+        let positions: Vec<Pos> = (0..self.height).map(|y| (x, y)).collect();
+        self.rotate(&positions, by);
+    }
+
+    /// The up to 4 positions next to `pos` on the grid: up, right, down, left.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The up to 8 positions around `pos` on the grid, diagonals included, clockwise from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// Every position on the grid, a row at a time.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell that isn't empty, with its position, a row at a time. Only the stored cells are
+    /// visited, so a sparse grid takes time for what's in it rather than for its size.
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let width = self.width;
+        self.cells.occupied().into_iter().map(move |(i, value)| ((i % width, i / width), value))
+    }
+
+    /// Where the first cell (a row at a time) matching `f` is.
+    pub fn position<F: Fn(&T) -> bool>(&self, f: F) -> Option<Pos> {
+        self.cells().find(|&(_, value)| f(value)).map(|(pos, _)| pos)
+    }
+
+    /// How many cells match `f`.
+    pub fn count<F: Fn(&T) -> bool>(&self, f: F) -> usize {
+        self.cells().filter(|&(_, value)| f(value)).count()
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        Some(pos.1 * self.width + pos.0).filter(|_| self.contains(pos))
+    }
+
+    fn rotate(&mut self, positions: &[Pos], by: usize) {
+        if positions.is_empty() {
+            return;
+        }
+
+        let mut values: Vec<Option<T>> = positions.iter().map(|&pos| self.clear(pos)).collect();
+        values.rotate_right(by % positions.len());
+
+        for (&pos, value) in positions.iter().zip(values) {
+            if let Some(value) = value {
+                self.set(pos, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::mem;
+
+/// Where a [`Grid`](crate::Grid)'s cells live. Cells are addressed by `y * width + x` and any of
+/// them may be empty.
+pub trait Storage<T> {
+    /// `len` empty cells.
+    fn with_len(len: usize) -> Self;
+
+    fn get(&self, i: usize) -> Option<&T>;
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut T>;
+
+    /// Put `value` in cell `i` (`None` empties it), returning what was there.
+    fn replace(&mut self, i: usize, value: Option<T>) -> Option<T>;
+
+    /// Every cell that isn't empty, with its index, in index order.
+    fn occupied(&self) -> Vec<(usize, &T)>;
+}
+
+/// Every cell stored, for grids that are mostly full: screens, bitmaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T>(Vec<Option<T>>);
+
+/// Only the occupied cells stored, for grids that are mostly empty or oddly shaped: keypads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<T>(HashMap<usize, T>);
+
+impl<T> Storage<T> for Dense<T> {
+    fn with_len(len: usize) -> Self {
+        Dense((0..len).map(|_| None).collect())
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        self.0.get(i)?.as_ref()
+    }
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.0.get_mut(i)?.as_mut()
+    }
+
+    fn replace(&mut self, i: usize, value: Option<T>) -> Option<T> {
+        mem::replace(&mut self.0[i], value)
+    }
+
+    fn occupied(&self) -> Vec<(usize, &T)> {
+        self.0.iter().enumerate().filter_map(|(i, cell)| cell.as_ref().map(|value| (i, value))).collect()
+    }
+}
+
+impl<T> Storage<T> for Sparse<T> {
+    fn with_len(_len: usize) -> Self {
+        Sparse(HashMap::new())
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        self.0.get(&i)
+    }
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.0.get_mut(&i)
+    }
+
+    fn replace(&mut self, i: usize, value: Option<T>) -> Option<T> {
+        match value {
+            Some(value) => self.0.insert(i, value),
+            None => self.0.remove(&i),
+        }
+    }
+
+    fn occupied(&self) -> Vec<(usize, &T)> {
+        let mut cells: Vec<(usize, &T)> = self.0.iter().map(|(&i, value)| (i, value)).collect();
+        cells.sort_unstable_by_key(|&(i, _)| i);
+        cells
+    }
+}
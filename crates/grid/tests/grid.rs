@@ -0,0 +1,90 @@
+use grid::{Grid, SparseGrid};
+
+const KEYPAD: &str = "
+  1
+ 234
+56789
+ ABC
+  D
+";
+
+fn keypad() -> SparseGrid<char> {
+    SparseGrid::parse(KEYPAD, |c| Some(c).filter(|c| !c.is_whitespace()))
+}
+
+#[test]
+fn parse_and_render() {
+    let keypad = keypad();
+
+    assert_eq!((keypad.width(), keypad.height()), (5, 5));
+    assert_eq!(keypad.get((2, 0)), Some(&'1'));
+    assert_eq!(keypad.get((0, 0)), None);
+    assert_eq!(keypad.position(|&c| c == '5'), Some((0, 2)));
+    assert_eq!(keypad.render(|c| c.copied().unwrap_or(' ')), "  1  \n 234 \n56789\n ABC \n  D  ");
+}
+
+#[test]
+fn bounds_and_wrapping() {
+    let keypad = keypad();
+
+    assert_eq!(keypad.get((5, 2)), None);
+    assert_eq!(keypad.get_wrapping(-1, 2), Some(&'9'));
+    assert_eq!(keypad.get_wrapping(2, 5), Some(&'1'));
+    assert_eq!(keypad.step((0, 2), (-1, 0)), None);
+    assert_eq!(keypad.step((0, 2), (1, 0)), Some((1, 2)));
+
+    // Nothing to wrap onto.
+    let empty = SparseGrid::parse("", Some);
+    assert_eq!(empty.wrap(3, -1), None);
+    assert_eq!(empty.get_wrapping(0, 0), None);
+    assert_eq!(Grid::<u8>::new(0, 4).wrap(0, 0), None);
+}
+
+#[test]
+fn sparse_cells_are_only_whats_stored() {
+    // Walking every position of this would take hours.
+    let mut grid: SparseGrid<char> = SparseGrid::new(1 << 20, 1 << 20);
+    grid.set((7, 900_000), 'b');
+    grid.set((1_000_000, 3), 'a');
+    grid.set((2, 900_000), 'c');
+
+    assert_eq!(grid.cells().collect::<Vec<_>>(), vec![((1_000_000, 3), &'a'), ((2, 900_000), &'c'), ((7, 900_000), &'b')]);
+    assert_eq!(grid.position(|&c| c == 'b'), Some((7, 900_000)));
+    assert_eq!(grid.count(|&c| c != 'a'), 2);
+}
+
+#[test]
+fn neighbours() {
+    let grid: Grid<u8> = Grid::filled(3, 3, 0);
+
+    assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8((2, 2)).collect::<Vec<_>>(), vec![(2, 1), (1, 2), (1, 1)]);
+}
+
+#[test]
+fn rows_columns_and_rotation() {
+    // The example from 2016 day 8.
+    let mut screen: Grid<bool> = Grid::filled(7, 3, false);
+    for pos in [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)] {
+        screen.set(pos, true);
+    }
+
+    screen.rotate_col(1, 1);
+    screen.rotate_row(0, 4);
+    screen.rotate_col(1, 1);
+
+    let art = screen.render(|lit| if lit == Some(&true) { '#' } else { '.' });
+    assert_eq!(art, ".#..#.#\n#.#....\n.#.....");
+    assert_eq!(screen.count(|&lit| lit), 6);
+    assert_eq!(screen.col(1).filter(|&lit| lit == Some(&true)).count(), 2);
+    assert_eq!(screen.row(2).filter(|&lit| lit == Some(&true)).count(), 1);
+}
+
+#[test]
+fn dense_and_sparse_agree() {
+    let dense: Grid<char> = Grid::parse(KEYPAD, |c| Some(c).filter(|c| !c.is_whitespace()));
+    let sparse = keypad();
+
+    assert_eq!(dense.cells().collect::<Vec<_>>(), sparse.cells().collect::<Vec<_>>());
+}
@@ -0,0 +1,121 @@
+//! The knot hash from 2017 day 10, which day 14 builds its disk grid from.
+//!
+//! A [`List`] of marks is twisted by a sequence of lengths; one pass over the lengths is a sparse
+//! round. The full hash runs 64 rounds over the input's bytes (plus a fixed suffix) on a list of
+//! 256 marks, then XORs each block of 16 marks down to one byte of the 16-byte digest.
+
+use std::fmt;
+
+/// Appended to the input's bytes before hashing.
+const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// Rounds in a full hash.
+const ROUNDS: usize = 64;
+
+/// Marks in the list a full hash twists.
+const SIZE: usize = 256;
+
+/// A circular list of marks, numbered `0..len` to begin with, and where the next twist starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List {
+    marks: Vec<u32>,
+    index: usize,
+    skip_size: usize,
+}
+
+impl List {
+    pub fn new(len: usize) -> List {
+        List { marks: (0..len as u32).collect(), index: 0, skip_size: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.marks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// The mark at `i`, wrapping around the end of the list.
+    pub fn get(&self, i: usize) -> u32 {
+        self.marks[i % self.marks.len()]
+    }
+
+    pub fn marks(&self) -> &[u32] {
+        &self.marks
+    }
+
+    /// Reverse the `length` marks starting at the current position, then move past them (and the
+    /// skip size, which grows by one every twist).
+    pub fn twist(&mut self, length: usize) {
+        assert!(length <= self.len(), "can't twist {} marks of a list of {}", length, self.len());
+
+        let len = self.len();
+        for i in 0..length / 2 {
+            self.marks.swap((self.index + i) % len, (self.index + length - 1 - i) % len);
+        }
+
+        self.index = (self.index + length + self.skip_size) % len;
+        self.skip_size += 1;
+    }
+
+    /// One sparse round: a twist for each length.
+    pub fn round(&mut self, lengths: &[usize]) {
+        for &length in lengths {
+            self.twist(length);
+        }
+    }
+
+    /// Each block of 16 marks XORed together.
+    pub fn dense_hash(&self) -> Vec<u8> {
+        self.marks.chunks(16).map(|chunk| chunk.iter().fold(0, |acc, m| acc ^ *m as u8)).collect()
+    }
+}
+
+/// The lengths a full hash twists by: `input`'s bytes plus the standard suffix.
+pub fn lengths(input: &str) -> Vec<usize> {
+    input.bytes().map(usize::from).chain(SUFFIX).collect()
+}
+
+/// The list after all 64 rounds over `input`, before it's condensed.
+pub fn sparse_hash(input: &str) -> List {
+    let lengths = lengths(input);
+    let mut list = List::new(SIZE);
+
+    for _ in 0..ROUNDS {
+        list.round(&lengths);
+    }
+
+    list
+}
+
+/// The 16-byte knot hash of `input`.
+pub fn digest(input: &str) -> [u8; 16] {
+    let mut digest = [0; 16];
+    digest.copy_from_slice(&sparse_hash(input).dense_hash());
+    digest
+}
+
+/// `bytes` as lowercase hex, two digits each.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The knot hash of `input` as 32 hex digits, the way the puzzle writes it.
+pub fn hex(input: &str) -> String {
+    to_hex(&digest(input))
+}
+
+/// `0 1 [2] 3 4`, with the current position in brackets, as in the puzzle.
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let marks: Vec<String> = self
+            .marks
+            .iter()
+            .enumerate()
+            .map(|(i, m)| if i == self.index { format!("[{}]", m) } else { m.to_string() })
+            .collect();
+
+        write!(f, "{}", marks.join(" "))
+    }
+}
@@ -0,0 +1,34 @@
+//! The examples from the 2017 day 10 puzzle text.
+
+use knot_hash::List;
+
+#[test]
+fn sparse_round() {
+    let mut list = List::new(5);
+    list.round(&[3, 4, 1, 5]);
+
+    assert_eq!(list.to_string(), "3 4 2 1 [0]");
+    assert_eq!(list.get(0) * list.get(1), 12);
+}
+
+#[test]
+fn hex() {
+    assert_eq!(knot_hash::hex(""), "a2582a3a0e66e6e86e3812dcb672a272");
+    assert_eq!(knot_hash::hex("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+    assert_eq!(knot_hash::hex("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
+    assert_eq!(knot_hash::hex("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+}
+
+#[test]
+fn digest() {
+    let digest = knot_hash::digest("AoC 2017");
+
+    assert_eq!(digest[0], 0x33);
+    assert_eq!(digest[15], 0xcd);
+    assert_eq!(knot_hash::to_hex(&digest), knot_hash::hex("AoC 2017"));
+}
+
+#[test]
+fn lengths() {
+    assert_eq!(knot_hash::lengths("1,2,3"), vec![49, 44, 50, 44, 51, 17, 31, 73, 47, 23]);
+}
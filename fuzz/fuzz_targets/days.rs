@@ -0,0 +1,25 @@
+//! Any day, picked by the first byte, has to return an error for junk rather than panic, and solve
+//! whatever it does parse without panicking either.
+
+#![no_main]
+
+use aoc::registry;
+use aoc_core::Part;
+use libfuzzer_sys::fuzz_target;
+
+/// Solving hashes or generates millions of times over whatever the input, far too slow to fuzz.
+const SLOW_TO_SOLVE: &[(u16, u8)] = &[(2016, 5), (2017, 15)];
+
+fuzz_target!(|input: (u8, &str)| {
+    let (pick, text) = input;
+    let days = registry::days();
+    let day = days[pick as usize % days.len()];
+
+    if let Ok(parsed) = day.parse(text) {
+        if !SLOW_TO_SOLVE.contains(&(day.year, day.day)) {
+            for part in Part::BOTH {
+                day.solve_part(&parsed, part);
+            }
+        }
+    }
+});
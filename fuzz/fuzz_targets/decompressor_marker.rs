@@ -0,0 +1,13 @@
+//! 2016/9 markers, "(10x2)".
+//! Whatever parses has to print as something that parses back the same.
+
+#![no_main]
+
+use aoc_2016_day_9::Marker;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(value) = Marker::parse(text) {
+        assert_eq!(Marker::parse(&value.to_string()).ok(), Some(value));
+    }
+});
@@ -0,0 +1,13 @@
+//! 2017/7 tower lines, "fwft (72) -> ktlj, cntj, xhth".
+//! Whatever parses has to print as something that parses back the same.
+
+#![no_main]
+
+use aoc_2017_day_7::ProgramReference;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(value) = ProgramReference::parse(text) {
+        assert_eq!(ProgramReference::parse(&value.to_string()).ok(), Some(value));
+    }
+});
@@ -0,0 +1,13 @@
+//! 2017/8 register instructions, "b inc 5 if a > 1".
+//! Whatever parses has to print as something that parses back the same.
+
+#![no_main]
+
+use aoc_2017_day_8::Instruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(value) = Instruction::parse(text) {
+        assert_eq!(Instruction::parse(&value.to_string()).ok(), Some(value));
+    }
+});
@@ -0,0 +1,13 @@
+//! 2016/8 screen operations, "rotate row y=0 by 4".
+//! Whatever parses has to print as something that parses back the same.
+
+#![no_main]
+
+use aoc_2016_day_8::Operation;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(value) = Operation::parse(text) {
+        assert_eq!(Operation::parse(&value.to_string()).ok(), Some(value));
+    }
+});
@@ -0,0 +1,13 @@
+//! 2016/1 instructions, "R8".
+//! Whatever parses has to print as something that parses back the same.
+
+#![no_main]
+
+use aoc_2016_day_1::Instruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(value) = Instruction::parse(text) {
+        assert_eq!(Instruction::parse(&value.to_string()).ok(), Some(value));
+    }
+});
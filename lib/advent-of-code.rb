@@ -83,7 +83,7 @@ module AdventOfCode
   def detect_language(d)
     case
     when File.exist?(File.join(d, 'main.go')) then :go
-    when File.exist?(File.join(d, 'src', 'main.rs')) then :rust
+    when File.exist?(File.join(d, 'Cargo.toml')) then :rust
     when File.exist?(File.join(d, 'Package.swift')) then :swift
     when File.exist?(File.join(d, 'main.py')) then :python
     when File.exist?(File.join(d, 'main.js')) then :javascript
@@ -202,7 +202,10 @@ END
 
     when :rust
       Proc.new do |project_path, date|
-        raise "Not Implemented!"
+        # `aoc new` makes the crate itself (always as day-N) and registers it with the workspace
+        FileUtils.rmdir(project_path)
+        system("cargo", "run", "--quiet", "--release", "-p", "aoc", "--", "new", "#{date.year}/#{date.day}") or
+          raise "aoc new #{date.year}/#{date.day} failed"
       end
 
     when :swift